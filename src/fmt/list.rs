@@ -1,6 +1,7 @@
 use core::fmt::{DebugList, Display};
 
 use super::fold_mut::IteratorFoldMut;
+use crate::types::ByteStr;
 use crate::wrap::DisplayAsDebug;
 
 /// Extension trait for [`DebugList`] providing convenient entry formatting methods.
@@ -51,6 +52,28 @@ pub trait DebugListExt {
     /// assert_eq!(format!("{:?}", list), "[Display(()), Display(())]");
     /// ```
     fn entries_display<I: IntoIterator<Item: Display>>(&mut self, iter: I) -> &mut Self;
+
+    /// Adds an entry formatting bytes as a byte string, like `b"text\xff"`. See [`ByteStr`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugListExt;
+    /// use std::fmt::{Debug, Formatter};
+    ///
+    /// struct Frames(Vec<Vec<u8>>);
+    ///
+    /// impl Debug for Frames {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         self.0.iter().fold(&mut f.debug_list(), |list, frame| list.entry_bytes(frame)).finish()
+    ///     }
+    /// }
+    ///
+    /// let frames = Frames(vec![b"ping".to_vec(), vec![0xff]]);
+    ///
+    /// assert_eq!(format!("{frames:?}"), r#"[b"ping", b"\xff"]"#);
+    /// ```
+    fn entry_bytes(&mut self, value: &[u8]) -> &mut Self;
 }
 
 #[sealed::sealed]
@@ -62,4 +85,8 @@ impl DebugListExt for DebugList<'_, '_> {
     fn entries_display<I: IntoIterator<Item: Display>>(&mut self, iter: I) -> &mut Self {
         iter.into_iter().fold_mut(self, |this, item| _ = this.entry_display(&item))
     }
+
+    fn entry_bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.entry(&ByteStr::new(value))
+    }
 }
@@ -1,7 +1,8 @@
 use core::fmt::{Debug, DebugStruct, Display};
+use core::time::Duration;
 
-use crate::types::{DisplayMode, OPAQUE, TypeName};
-use crate::wrap::DisplayAsDebug;
+use crate::types::{Addr, Base64, ByteStr, DisplayMode, FnName, HumanDuration, IpAddress, MaskedIp, OPAQUE, TypeName};
+use crate::wrap::{DisplayAsDebug, FormatAs, Formatted};
 
 /// Extension trait for [`DebugStruct`] providing convenient field formatting methods.
 #[sealed::sealed]
@@ -78,6 +79,180 @@ pub trait DebugStructExt {
     /// assert_eq!(format!("{:?}", creds), "Credentials { password: .. }");
     /// ```
     fn field_opaque(&mut self, name: &str) -> &mut Self;
+
+    /// Adds a field formatted according to a runtime-selected [`FormatAs`] strategy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use display_as_debug::wrap::{FormatAs, TypeNameMode};
+    /// use std::fmt::{Debug, Formatter};
+    ///
+    /// struct User { name: &'static str, email: &'static str, redact: bool }
+    ///
+    /// impl Debug for User {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         let email = if self.redact { FormatAs::Opaque } else { FormatAs::Debug };
+    ///         f.debug_struct("User")
+    ///             .field_as("name", &self.name, FormatAs::Display)
+    ///             .field_as("email", &self.email, email)
+    ///             .finish()
+    ///     }
+    /// }
+    ///
+    /// let user = User { name: "alice", email: "alice@example.com", redact: true };
+    ///
+    /// assert_eq!(format!("{:?}", user), "User { name: alice, email: .. }");
+    /// ```
+    fn field_as<T: Debug + Display + ?Sized>(&mut self, name: &str, value: &T, format: FormatAs) -> &mut Self;
+
+    /// Adds a field showing the cleaned up name of a function or closure, using the specified
+    /// [`DisplayMode`]. See [`FnName`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use display_as_debug::types::Short;
+    /// use std::fmt::{Debug, Formatter};
+    ///
+    /// struct Button { label: &'static str, on_click: Box<dyn Fn()> }
+    ///
+    /// impl Debug for Button {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         f.debug_struct("Button")
+    ///             .field("label", &self.label)
+    ///             .field_fn::<Short>("on_click", &self.on_click)
+    ///             .finish()
+    ///     }
+    /// }
+    ///
+    /// let button = Button { label: "OK", on_click: Box::new(|| {}) };
+    ///
+    /// assert_eq!(format!("{:?}", button), r#"Button { label: "OK", on_click: dyn Fn() }"#);
+    /// ```
+    fn field_fn<M: DisplayMode>(&mut self, name: &str, value: &impl ?Sized) -> &mut Self;
+
+    /// Adds a field showing the type name and address of a value, using the specified
+    /// [`DisplayMode`]. See [`Addr`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use display_as_debug::types::Short;
+    /// use std::fmt::{Debug, Formatter};
+    /// use std::rc::Rc;
+    ///
+    /// struct Node { shared: Rc<Vec<u8>> }
+    ///
+    /// impl Debug for Node {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         f.debug_struct("Node").field_addr::<Short>("shared", &*self.shared).finish()
+    ///     }
+    /// }
+    ///
+    /// let shared = Rc::new(vec![1]);
+    /// let a = Node { shared: Rc::clone(&shared) };
+    /// let b = Node { shared };
+    ///
+    /// assert!(format!("{:?}", a).starts_with("Node { shared: Vec<u8>@0x"));
+    /// assert_eq!(format!("{:?}", a), format!("{:?}", b));
+    /// ```
+    fn field_addr<M: DisplayMode>(&mut self, name: &str, value: &impl ?Sized) -> &mut Self;
+
+    /// Adds a field formatting bytes as a byte string, like `b"text\xff"`. See [`ByteStr`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use std::fmt::{Debug, Formatter};
+    ///
+    /// struct Packet { payload: Vec<u8> }
+    ///
+    /// impl Debug for Packet {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         f.debug_struct("Packet").field_bytes("payload", &self.payload).finish()
+    ///     }
+    /// }
+    ///
+    /// let packet = Packet { payload: b"GET /\r\n".to_vec() };
+    ///
+    /// assert_eq!(format!("{:?}", packet), r#"Packet { payload: b"GET /\r\n" }"#);
+    /// ```
+    fn field_bytes(&mut self, name: &str, value: &[u8]) -> &mut Self;
+
+    /// Adds a field formatting bytes as padded, standard Base64. See [`Base64`] for other formats.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use std::fmt::{Debug, Formatter};
+    ///
+    /// struct Session { id: u32, nonce: [u8; 4] }
+    ///
+    /// impl Debug for Session {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         f.debug_struct("Session").field("id", &self.id).field_base64("nonce", &self.nonce).finish()
+    ///     }
+    /// }
+    ///
+    /// let session = Session { id: 7, nonce: [0xde, 0xad, 0xbe, 0xef] };
+    ///
+    /// assert_eq!(format!("{:?}", session), "Session { id: 7, nonce: 3q2+7w== }");
+    /// ```
+    fn field_base64(&mut self, name: &str, value: &[u8]) -> &mut Self;
+
+    /// Adds a field formatting a [`Duration`] in human-readable units, like `1h 30m`. See
+    /// [`HumanDuration`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use std::fmt::{Debug, Formatter};
+    /// use std::time::Duration;
+    ///
+    /// struct Job { name: &'static str, elapsed: Duration }
+    ///
+    /// impl Debug for Job {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         f.debug_struct("Job").field("name", &self.name).field_duration("elapsed", &self.elapsed).finish()
+    ///     }
+    /// }
+    ///
+    /// let job = Job { name: "backup", elapsed: Duration::from_secs(5_400) };
+    ///
+    /// assert_eq!(format!("{:?}", job), r#"Job { name: "backup", elapsed: 1h 30m }"#);
+    /// ```
+    fn field_duration(&mut self, name: &str, value: &Duration) -> &mut Self;
+
+    /// Adds a field formatting an IP or socket address with its host bits masked, like
+    /// `203.0.113.x`. See [`MaskedIp`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use display_as_debug::fmt::DebugStructExt;
+    /// use std::fmt::{Debug, Formatter};
+    /// use std::net::{Ipv4Addr, SocketAddr};
+    ///
+    /// struct Request { path: &'static str, client: SocketAddr }
+    ///
+    /// impl Debug for Request {
+    ///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    ///         f.debug_struct("Request").field("path", &self.path).field_ip_masked("client", &self.client).finish()
+    ///     }
+    /// }
+    ///
+    /// let request = Request { path: "/login", client: (Ipv4Addr::new(203, 0, 113, 42), 5555).into() };
+    ///
+    /// assert_eq!(format!("{:?}", request), r#"Request { path: "/login", client: 203.0.113.x:5555 }"#);
+    /// ```
+    fn field_ip_masked<A: IpAddress>(&mut self, name: &str, value: &A) -> &mut Self;
 }
 
 #[sealed::sealed]
@@ -96,4 +271,32 @@ impl DebugStructExt for DebugStruct<'_, '_> {
     fn field_opaque(&mut self, name: &str) -> &mut Self {
         self.field(name, &OPAQUE)
     }
+
+    fn field_as<T: Debug + Display + ?Sized>(&mut self, name: &str, value: &T, format: FormatAs) -> &mut Self {
+        self.field(name, &Formatted(value, format))
+    }
+
+    fn field_fn<M: DisplayMode>(&mut self, name: &str, value: &impl ?Sized) -> &mut Self {
+        self.field(name, &FnName::of::<M>(value))
+    }
+
+    fn field_addr<M: DisplayMode>(&mut self, name: &str, value: &impl ?Sized) -> &mut Self {
+        self.field(name, &Addr::new::<M>(value))
+    }
+
+    fn field_bytes(&mut self, name: &str, value: &[u8]) -> &mut Self {
+        self.field(name, &ByteStr::new(value))
+    }
+
+    fn field_base64(&mut self, name: &str, value: &[u8]) -> &mut Self {
+        self.field(name, &Base64::new(value))
+    }
+
+    fn field_duration(&mut self, name: &str, value: &Duration) -> &mut Self {
+        self.field(name, &HumanDuration::new(*value))
+    }
+
+    fn field_ip_masked<A: IpAddress>(&mut self, name: &str, value: &A) -> &mut Self {
+        self.field(name, &MaskedIp::new(*value))
+    }
 }
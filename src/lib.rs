@@ -4,6 +4,11 @@
 #![warn(missing_docs, missing_debug_implementations)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(doc)]
 use core::fmt::{Debug, Display};
 
@@ -15,3 +20,6 @@ pub mod types;
 
 /// Wrapper types for [`Debug`] and [`Display`] format conversions.
 pub mod wrap;
+
+/// Allocation-free [`Write`](core::fmt::Write) sinks for measuring and buffering formatted output.
+pub mod write;
@@ -0,0 +1,147 @@
+//! Implementation of [`Addr`] and `SharedAddr`
+
+use core::fmt::{Debug, Formatter, Pointer, Result};
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+use crate::types::{DisplayMode, Short};
+
+/// A type that formats as the type name and address of a borrowed value, like `Vec<i32>@0x7ffd...`,
+/// when used in [`Debug`] contexts.
+///
+/// Useful for telling apart values that are equal, but are not the same allocation, such as when
+/// chasing aliasing bugs. For the pointee of an `Rc` or `Arc`, pass the dereferenced value (`&*rc`),
+/// or see `SharedAddr` (requires the `alloc` feature).
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::{Addr, Short};
+/// let value = vec![1, 2, 3];
+/// let addr = format!("{:?}", Addr::new::<Short>(&value));
+/// assert!(addr.starts_with("Vec<i32>@0x"));
+///
+/// let alias = &value;
+/// assert_eq!(format!("{:?}", Addr::new::<Short>(alias)), addr);
+/// assert_ne!(format!("{:?}", Addr::new::<Short>(&value.clone())), addr);
+/// ```
+pub struct Addr<'a, T: ?Sized, M: DisplayMode = Short>(pub &'a T, PhantomData<M>);
+
+impl<'a, T: ?Sized> Addr<'a, T> {
+    /// Creates an [`Addr`] for the given value, showing its type name using the given
+    /// [`DisplayMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::{Addr, Full};
+    /// let addr = format!("{:?}", Addr::new::<Full>(&vec![1]));
+    /// assert!(addr.starts_with("alloc::vec::Vec<i32>@0x"));
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(value: &'a T) -> Addr<'a, T, M> {
+        Addr(value, PhantomData)
+    }
+}
+
+impl<T: ?Sized, M: DisplayMode> Clone for Addr<'_, T, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, M: DisplayMode> Copy for Addr<'_, T, M> {}
+
+impl<T: ?Sized, M: DisplayMode> Debug for Addr<'_, T, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(M::type_name::<T>())?;
+        f.write_str("@")?;
+        Pointer::fmt(&core::ptr::from_ref(self.0).cast::<()>(), f)
+    }
+}
+
+/// A type that formats an [`Rc`] or [`Arc`] showing the address of its allocation and its strong
+/// and weak counts, alongside its value, when used in [`Debug`] contexts.
+///
+/// Clones of the same [`Rc`] or [`Arc`] share the same address.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// # use display_as_debug::types::{SharedAddr, Short};
+/// let shared = Rc::new(42);
+/// let clone = Rc::clone(&shared);
+/// let weak = Rc::downgrade(&shared);
+///
+/// let debug = format!("{:?}", SharedAddr::new::<Short>(&shared));
+/// assert!(debug.starts_with("Rc { addr: i32@0x"));
+/// assert!(debug.ends_with(", strong: 2, weak: 1, value: 42 }"));
+/// assert_eq!(format!("{:?}", SharedAddr::new::<Short>(&clone)), debug);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SharedAddr<'a, P: ?Sized, M: DisplayMode = Short>(pub &'a P, PhantomData<M>);
+
+#[cfg(feature = "alloc")]
+impl<'a, P: ?Sized> SharedAddr<'a, P> {
+    /// Creates a [`SharedAddr`] for the given [`Rc`] or [`Arc`], showing the type name of its value
+    /// using the given [`DisplayMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use display_as_debug::types::{SharedAddr, Full};
+    /// let debug = format!("{:?}", SharedAddr::new::<Full>(&Arc::new("text")));
+    /// assert!(debug.starts_with("Arc { addr: &str@0x"));
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(shared: &'a P) -> SharedAddr<'a, P, M> {
+        SharedAddr(shared, PhantomData)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: ?Sized, M: DisplayMode> Clone for SharedAddr<'_, P, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P: ?Sized, M: DisplayMode> Copy for SharedAddr<'_, P, M> {}
+
+#[cfg(feature = "alloc")]
+impl<T: Debug + ?Sized, M: DisplayMode> Debug for SharedAddr<'_, Rc<T>, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_shared(f, "Rc", Addr::new::<M>(&**self.0), Rc::strong_count(self.0), Rc::weak_count(self.0))
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: Debug + ?Sized, M: DisplayMode> Debug for SharedAddr<'_, Arc<T>, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_shared(f, "Arc", Addr::new::<M>(&**self.0), Arc::strong_count(self.0), Arc::weak_count(self.0))
+    }
+}
+
+/// Formats a shared pointer with its address, counts and value.
+#[cfg(feature = "alloc")]
+fn fmt_shared<T: Debug + ?Sized, M: DisplayMode>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    addr: Addr<'_, T, M>,
+    strong: usize,
+    weak: usize,
+) -> Result {
+    f.debug_struct(name)
+        .field("addr", &addr)
+        .field("strong", &strong)
+        .field("weak", &weak)
+        .field("value", &addr.0)
+        .finish()
+}
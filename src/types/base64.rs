@@ -0,0 +1,136 @@
+//! Implementation of [`Base64`]
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use core::marker::PhantomData;
+
+/// Sealed marker trait for [`Base64`] alphabets.
+#[sealed::sealed]
+pub trait Base64Alphabet {
+    /// The 64 characters used to encode each 6-bit group, in order.
+    const ALPHABET: &'static [u8; 64];
+}
+
+/// [`Base64Alphabet`] using `+` and `/`, as defined by RFC 4648 section 4.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StandardAlphabet;
+
+#[sealed::sealed]
+impl Base64Alphabet for StandardAlphabet {
+    const ALPHABET: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+}
+
+/// [`Base64Alphabet`] using `-` and `_`, safe for URLs and file names, as defined by RFC 4648
+/// section 5.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UrlSafeAlphabet;
+
+#[sealed::sealed]
+impl Base64Alphabet for UrlSafeAlphabet {
+    const ALPHABET: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+}
+
+/// A type that formats bytes as Base64, for compact output of keys, nonces and hashes that can be
+/// copied into other tools.
+///
+/// The bytes are encoded as they are written to the [`Formatter`], no allocation is performed.
+///
+/// # Type Parameters
+///
+/// - `T`: The bytes to encode.
+/// - `A`: The [`Base64Alphabet`], [`StandardAlphabet`] by default.
+/// - `PAD`: Whether the output is padded with `=` to a multiple of 4 characters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::Base64;
+/// assert_eq!(format!("{}", Base64::new(b"hello?")), "aGVsbG8/");
+/// assert_eq!(format!("{}", Base64::new(b"hi")), "aGk=");
+///
+/// assert_eq!(format!("{:?}", Base64::new(b"hello?").url_safe()), "aGVsbG8_");
+/// assert_eq!(format!("{:?}", Base64::new(b"hi").unpadded()), "aGk");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the bytes as Base64
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Base64<T, A: Base64Alphabet = StandardAlphabet, const PAD: bool = true>(
+    /// The bytes to encode.
+    pub T,
+    PhantomData<A>,
+);
+
+impl<T> Base64<T> {
+    /// Creates a new [`Base64`] using the [`StandardAlphabet`], with padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Base64;
+    /// assert_eq!(format!("{}", Base64::new([0xfb, 0xff])), "+/8=");
+    /// ```
+    #[must_use]
+    pub const fn new(bytes: T) -> Self {
+        Self(bytes, PhantomData)
+    }
+}
+
+impl<T, A: Base64Alphabet, const PAD: bool> Base64<T, A, PAD> {
+    /// Switches to the [`UrlSafeAlphabet`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Base64;
+    /// assert_eq!(format!("{}", Base64::new([0xfb, 0xff]).url_safe()), "-_8=");
+    /// ```
+    #[must_use]
+    pub fn url_safe(self) -> Base64<T, UrlSafeAlphabet, PAD> {
+        Base64(self.0, PhantomData)
+    }
+
+    /// Switches to unpadded output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Base64;
+    /// assert_eq!(format!("{}", Base64::new([0xfb, 0xff]).unpadded()), "+/8");
+    /// ```
+    #[must_use]
+    pub fn unpadded(self) -> Base64<T, A, false> {
+        Base64(self.0, PhantomData)
+    }
+}
+
+impl<T: AsRef<[u8]>, A: Base64Alphabet, const PAD: bool> Display for Base64<T, A, PAD> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut chunks = self.0.as_ref().chunks_exact(3);
+        chunks.by_ref().try_for_each(|chunk| write_group::<A>(f, [chunk[0], chunk[1], chunk[2]], 4))?;
+
+        let (group, len, padding) = match *chunks.remainder() {
+            [a] => ([a, 0, 0], 2, "=="),
+            [a, b] => ([a, b, 0], 3, "="),
+            _ => return Ok(()),
+        };
+        write_group::<A>(f, group, len)?;
+        if PAD { f.write_str(padding) } else { Ok(()) }
+    }
+}
+
+impl<T: AsRef<[u8]>, A: Base64Alphabet, const PAD: bool> Debug for Base64<T, A, PAD> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Writes the first `len` characters encoding a group of 3 bytes.
+fn write_group<A: Base64Alphabet>(f: &mut Formatter<'_>, [a, b, c]: [u8; 3], len: usize) -> Result {
+    let group = u32::from(a) << 16 | u32::from(b) << 8 | u32::from(c);
+    [18, 12, 6, 0].into_iter().take(len).try_for_each(|shift| {
+        let index = (group >> shift) & 0x3f;
+        f.write_char(char::from(A::ALPHABET[index as usize]))
+    })
+}
@@ -0,0 +1,138 @@
+//! Implementation of [`ByteSize`] and [`DecimalByteSize`]
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+use crate::types::pad::pad;
+use crate::write::FmtBuf;
+
+/// The largest supported precision, so that the scaled size fits in a [`u128`].
+const MAX_PRECISION: usize = 18;
+
+/// The longest output: a 4 digit whole part, an 18 digit fraction and a 3 character unit.
+const MAX_LEN: usize = 4 + 1 + MAX_PRECISION + 1 + 3;
+
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+/// A type that formats a number of bytes as a human-readable size using binary (IEC) units, like
+/// `70.0 MiB`.
+///
+/// The number of decimal places can be set with the formatter's precision flag (1 by default, up
+/// to 18). Sizes below 1 KiB are shown as whole bytes. The formatter's width, fill and alignment
+/// are supported, left aligned by default. See [`DecimalByteSize`] for decimal (SI) units.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::ByteSize;
+/// assert_eq!(format!("{}", ByteSize(73_400_320)), "70.0 MiB");
+/// assert_eq!(format!("{:.2}", ByteSize(1_500)), "1.46 KiB");
+/// assert_eq!(format!("{:.0}", ByteSize(1_500)), "1 KiB");
+/// assert_eq!(format!("{:?}", ByteSize(512)), "512 B");
+/// assert_eq!(format!("[{:>9}]", ByteSize(1_024)), "[  1.0 KiB]");
+/// ```
+///
+/// Alongside [`OpaqueList`](crate::types::OpaqueList), to summarize a buffer:
+///
+/// ```rust
+/// # use display_as_debug::types::{ByteSize, OpaqueList};
+/// #[derive(Debug)]
+/// struct Chunks { chunks: OpaqueList, total: ByteSize }
+///
+/// let chunks = Chunks { chunks: OpaqueList(12), total: ByteSize(3 << 20) };
+/// assert_eq!(format!("{chunks:?}"), "Chunks { chunks: [..: 12], total: 3.0 MiB }");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the size with binary units
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct ByteSize(pub u64);
+
+impl Display for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_size(self.0, 1024, &BINARY_UNITS, f)
+    }
+}
+
+impl Debug for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// A type that formats a number of bytes as a human-readable size using decimal (SI) units, like
+/// `73.4 MB`.
+///
+/// The number of decimal places can be set with the formatter's precision flag (1 by default, up
+/// to 18). Sizes below 1 kB are shown as whole bytes. The formatter's width, fill and alignment
+/// are supported, left aligned by default. See [`ByteSize`] for binary units.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::DecimalByteSize;
+/// assert_eq!(format!("{}", DecimalByteSize(73_400_320)), "73.4 MB");
+/// assert_eq!(format!("{:.3}", DecimalByteSize(1_500)), "1.500 kB");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the size with decimal units
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct DecimalByteSize(pub u64);
+
+impl Display for DecimalByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_size(self.0, 1000, &DECIMAL_UNITS, f)
+    }
+}
+
+impl Debug for DecimalByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Formats `bytes` in the largest unit it is at least one of, rounded to the formatter's precision,
+/// and padded to its width.
+fn fmt_size(bytes: u64, base: u128, units: &[&str], f: &mut Formatter<'_>) -> Result {
+    let mut buf = FmtBuf::<MAX_LEN>::new();
+    write_size(&mut buf, bytes, base, units, f.precision().unwrap_or(1).min(MAX_PRECISION))?;
+    pad(f, buf.as_str())
+}
+
+/// Writes `bytes` in the largest unit it is at least one of, rounded to `precision` digits.
+fn write_size(w: &mut impl Write, bytes: u64, base: u128, units: &[&str], precision: usize) -> Result {
+    let bytes = u128::from(bytes);
+
+    let mut unit = 0;
+    let mut divisor = 1;
+    while unit + 1 < units.len() && bytes >= divisor * base {
+        divisor *= base;
+        unit += 1;
+    }
+    if unit == 0 {
+        return write!(w, "{bytes} {}", units[0]);
+    }
+
+    // `precision` is at most `MAX_PRECISION`, so this cannot truncate
+    #[allow(clippy::cast_possible_truncation, reason = "precision is capped")]
+    let scale = 10_u128.pow(precision as u32);
+    let mut scaled = (bytes * scale + divisor / 2) / divisor;
+    // rounding may carry into the next unit, such as 1023.96 KiB to 1024.0 KiB
+    if scaled >= base * scale && unit + 1 < units.len() {
+        divisor *= base;
+        unit += 1;
+        scaled = (bytes * scale + divisor / 2) / divisor;
+    }
+
+    match precision {
+        0 => write!(w, "{scaled} {}", units[unit]),
+        _ => write!(w, "{}.{:0precision$} {}", scaled / scale, scaled % scale, units[unit]),
+    }
+}
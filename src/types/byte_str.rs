@@ -0,0 +1,123 @@
+//! Implementation of [`ByteStr`]
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+
+/// The marker written after the bytes when they are truncated by [`ByteStr::max_len`].
+const TRUNCATED: &str = "..";
+
+/// A type that formats bytes as a byte string, instead of a list of numbers.
+///
+/// Useful for protocol buffers, keys and other binary data that is mostly text. The bytes can be
+/// capped to a maximum length, in which case the output ends with `..`. No allocation is performed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::ByteStr;
+/// let bytes = b"hello\xff\n";
+/// assert_eq!(format!("{:?}", ByteStr::new(bytes)), r#"b"hello\xff\n""#);
+/// assert_eq!(format!("{}", ByteStr::new(bytes)), "hello\u{FFFD}\n");
+///
+/// assert_eq!(format!("{:?}", ByteStr::new(bytes).with_max_len(4)), r#"b"hell".."#);
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Formats the bytes as an escaped byte string literal, like `b"text\x00"`
+/// - **[`Display`]**: Formats the bytes as lossy UTF-8, with invalid sequences replaced by
+///   [`U+FFFD`](char::REPLACEMENT_CHARACTER). A character split by the maximum length is left out.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteStr<T> {
+    /// The bytes to format.
+    pub bytes: T,
+    /// The maximum number of bytes to format, if any.
+    pub max_len: Option<usize>,
+}
+
+impl<T> ByteStr<T> {
+    /// Creates a new [`ByteStr`] formatting all of the given bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::ByteStr;
+    /// assert_eq!(format!("{:?}", ByteStr::new(vec![b'a', 0])), r#"b"a\0""#);
+    /// ```
+    #[must_use]
+    pub const fn new(bytes: T) -> Self {
+        Self { bytes, max_len: None }
+    }
+
+    /// Sets the maximum number of bytes to format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::ByteStr;
+    /// let bytes = ByteStr::new("a long line of text").with_max_len(6);
+    /// assert_eq!(format!("{bytes}"), "a long..");
+    /// ```
+    #[must_use]
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        Self { max_len: Some(max_len), ..self }
+    }
+}
+
+impl<T: AsRef<[u8]>> ByteStr<T> {
+    /// Returns the bytes to format, and whether they were truncated.
+    fn capped(&self) -> (&[u8], bool) {
+        let bytes = self.bytes.as_ref();
+        match self.max_len {
+            Some(max_len) if bytes.len() > max_len => (&bytes[..max_len], true),
+            _ => (bytes, false),
+        }
+    }
+
+    /// Returns the bytes to format as text, and whether they were truncated, leaving out a
+    /// character split by the truncation so it isn't shown as invalid.
+    fn capped_text(&self) -> (&[u8], bool) {
+        let (capped, truncated) = self.capped();
+        if !truncated {
+            return (capped, false);
+        }
+
+        // a split character starts at most 3 bytes before the end, on a non-continuation byte
+        let bytes = self.bytes.as_ref();
+        let end = capped.len();
+        let split = (end.saturating_sub(3)..end).rev().find(|&index| bytes[index] & 0xc0 != 0x80).filter(|&start| {
+            let first = bytes[start..].utf8_chunks().next().and_then(|chunk| chunk.valid().chars().next());
+            first.is_some_and(|first| start + first.len_utf8() > end)
+        });
+        (split.map_or(capped, |start| &capped[..start]), true)
+    }
+}
+
+impl<T: AsRef<[u8]>> Debug for ByteStr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (bytes, truncated) = self.capped();
+        f.write_str("b\"")?;
+        bytes.iter().try_for_each(|&byte| match byte {
+            b'"' => f.write_str("\\\""),
+            b'\\' => f.write_str("\\\\"),
+            b'\n' => f.write_str("\\n"),
+            b'\r' => f.write_str("\\r"),
+            b'\t' => f.write_str("\\t"),
+            b'\0' => f.write_str("\\0"),
+            b' '..=b'~' => f.write_char(char::from(byte)),
+            _ => write!(f, "\\x{byte:02x}"),
+        })?;
+        f.write_char('"')?;
+        if truncated { f.write_str(TRUNCATED) } else { Ok(()) }
+    }
+}
+
+impl<T: AsRef<[u8]>> Display for ByteStr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (bytes, truncated) = self.capped_text();
+        bytes.utf8_chunks().try_for_each(|chunk| {
+            f.write_str(chunk.valid())?;
+            if chunk.invalid().is_empty() { Ok(()) } else { f.write_char(char::REPLACEMENT_CHARACTER) }
+        })?;
+        if truncated { f.write_str(TRUNCATED) } else { Ok(()) }
+    }
+}
@@ -0,0 +1,19 @@
+//! Implementation of [`Count`]
+
+use core::fmt::Display;
+
+use crate::types::Grouped;
+
+/// Sealed trait for the counts of the opaque and type name collection types.
+///
+/// Implemented for a plain [`usize`] and a [`Grouped`] one, as shown by
+/// [`OpaqueList`](crate::types::OpaqueList), [`OpaqueSet`](crate::types::OpaqueSet),
+/// [`TypeNameList`](crate::types::TypeNameList) and [`TypeNameSet`](crate::types::TypeNameSet).
+#[sealed::sealed]
+pub trait Count: Display {}
+
+#[sealed::sealed]
+impl Count for usize {}
+
+#[sealed::sealed]
+impl Count for Grouped<usize> {}
@@ -0,0 +1,50 @@
+//! Implementation of [`DisplayList`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::fmt::DebugListExt;
+
+/// A borrowed collection that formats as a list, using the [`Display`] implementation of its
+/// elements when used with [`Debug`].
+///
+/// Saves writing a manual [`Debug`] implementation using
+/// [`DebugListExt::entries_display`]. Declaring a field as a [`DisplayList`] allows
+/// `#[derive(Debug)]` to be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::{DisplayList, TestValue};
+/// #[derive(Debug)]
+/// struct Report<'a> {
+///     values: DisplayList<'a, [TestValue<i32>]>,
+/// }
+///
+/// let values = [TestValue(1), TestValue(2)];
+/// let report = Report { values: DisplayList(&values) };
+/// assert_eq!(format!("{report:?}"), "Report { values: [Display(1), Display(2)] }");
+/// ```
+pub struct DisplayList<'a, C: ?Sized>(pub &'a C);
+
+impl<C: ?Sized> Clone for DisplayList<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for DisplayList<'_, C> {}
+
+impl<'a, C: ?Sized> From<&'a C> for DisplayList<'a, C> {
+    fn from(collection: &'a C) -> Self {
+        Self(collection)
+    }
+}
+
+impl<'a, C: ?Sized> Debug for DisplayList<'a, C>
+where
+    &'a C: IntoIterator<Item: Display>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries_display(self.0).finish()
+    }
+}
@@ -0,0 +1,51 @@
+//! Implementation of [`DisplayMap`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::fmt::DebugMapExt;
+
+/// A borrowed map that formats using the [`Debug`] implementation of its keys and the
+/// [`Display`] implementation of its values when used with [`Debug`].
+///
+/// Saves writing a manual [`Debug`] implementation using
+/// [`DebugMapExt::entries_display`]. Declaring a field as a [`DisplayMap`] allows
+/// `#[derive(Debug)]` to be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::{DisplayMap, TestValue};
+/// # use std::collections::BTreeMap;
+/// #[derive(Debug)]
+/// struct Report<'a> {
+///     values: DisplayMap<'a, BTreeMap<&'static str, TestValue<i32>>>,
+/// }
+///
+/// let values = BTreeMap::from([("a", TestValue(1)), ("b", TestValue(2))]);
+/// let report = Report { values: DisplayMap(&values) };
+/// assert_eq!(format!("{report:?}"), r#"Report { values: {"a": Display(1), "b": Display(2)} }"#);
+/// ```
+pub struct DisplayMap<'a, C: ?Sized>(pub &'a C);
+
+impl<C: ?Sized> Clone for DisplayMap<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for DisplayMap<'_, C> {}
+
+impl<'a, C: ?Sized> From<&'a C> for DisplayMap<'a, C> {
+    fn from(map: &'a C) -> Self {
+        Self(map)
+    }
+}
+
+impl<'a, C: ?Sized, K: Debug, V: Display> Debug for DisplayMap<'a, C>
+where
+    &'a C: IntoIterator<Item = (K, V)>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries_display(self.0).finish()
+    }
+}
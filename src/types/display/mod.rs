@@ -0,0 +1,10 @@
+//! Collection types using [`DisplayAsDebug`](crate::wrap::DisplayAsDebug) for specialized debug
+//! formatting.
+
+mod list;
+mod map;
+mod set;
+
+pub use list::DisplayList;
+pub use map::DisplayMap;
+pub use set::DisplaySet;
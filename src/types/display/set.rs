@@ -0,0 +1,51 @@
+//! Implementation of [`DisplaySet`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::fmt::DebugSetExt;
+
+/// A borrowed collection that formats as a set, using the [`Display`] implementation of its
+/// elements when used with [`Debug`].
+///
+/// Saves writing a manual [`Debug`] implementation using
+/// [`DebugSetExt::entries_display`]. Declaring a field as a [`DisplaySet`] allows
+/// `#[derive(Debug)]` to be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::{DisplaySet, TestValue};
+/// # use std::collections::BTreeSet;
+/// #[derive(Debug)]
+/// struct Report<'a> {
+///     values: DisplaySet<'a, BTreeSet<TestValue<i32>>>,
+/// }
+///
+/// let values = BTreeSet::from([TestValue(1), TestValue(2)]);
+/// let report = Report { values: DisplaySet(&values) };
+/// assert_eq!(format!("{report:?}"), "Report { values: {Display(1), Display(2)} }");
+/// ```
+pub struct DisplaySet<'a, C: ?Sized>(pub &'a C);
+
+impl<C: ?Sized> Clone for DisplaySet<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for DisplaySet<'_, C> {}
+
+impl<'a, C: ?Sized> From<&'a C> for DisplaySet<'a, C> {
+    fn from(collection: &'a C) -> Self {
+        Self(collection)
+    }
+}
+
+impl<'a, C: ?Sized> Debug for DisplaySet<'a, C>
+where
+    &'a C: IntoIterator<Item: Display>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_set().entries_display(self.0).finish()
+    }
+}
@@ -0,0 +1,336 @@
+//! Implementation of [`HumanDuration`] and [`IsoDuration`]
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use core::time::Duration;
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+use crate::types::pad::pad;
+use crate::write::FmtBuf;
+
+/// The largest supported precision, so that the scaled fraction fits in a [`u128`].
+const MAX_PRECISION: usize = 18;
+
+/// The largest precision of [`IsoDuration`], which is limited to nanoseconds.
+const MAX_ISO_PRECISION: usize = 9;
+
+/// The longest [`HumanDuration`] output: a 29 digit count of the largest unit, 6 smaller units of
+/// up to 3 digits after a separator, an 18 digit fraction, and a suffix of up to 3 bytes per unit.
+const MAX_HUMAN_LEN: usize = 29 + 6 * 4 + 1 + MAX_PRECISION + 7 * 3;
+
+/// The longest [`IsoDuration`] output: `PT`, 16 digits of hours, 2 of minutes and 2 of seconds
+/// with their designators, and a 9 digit fraction.
+const MAX_ISO_LEN: usize = 2 + 17 + 3 + 3 + 1 + MAX_ISO_PRECISION;
+
+/// A unit of time, used to select the largest and smallest units shown by a [`HumanDuration`].
+///
+/// Units are ordered from smallest to largest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    /// Nanoseconds, shown as `ns`.
+    Nanos,
+    /// Microseconds, shown as `µs`.
+    Micros,
+    /// Milliseconds, shown as `ms`.
+    Millis,
+    /// Seconds, shown as `s`.
+    Seconds,
+    /// Minutes, shown as `m`.
+    Minutes,
+    /// Hours, shown as `h`.
+    Hours,
+    /// Days of 24 hours, shown as `d`.
+    Days,
+}
+
+impl TimeUnit {
+    /// All units, from largest to smallest.
+    const DESCENDING: [Self; 7] =
+        [Self::Days, Self::Hours, Self::Minutes, Self::Seconds, Self::Millis, Self::Micros, Self::Nanos];
+
+    /// Returns the number of nanoseconds in one of this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::TimeUnit;
+    /// assert_eq!(TimeUnit::Millis.nanos(), 1_000_000);
+    /// assert_eq!(TimeUnit::Minutes.nanos(), 60_000_000_000);
+    /// ```
+    #[must_use]
+    pub const fn nanos(self) -> u128 {
+        match self {
+            Self::Nanos => 1,
+            Self::Micros => 1_000,
+            Self::Millis => 1_000_000,
+            Self::Seconds => 1_000_000_000,
+            Self::Minutes => 60 * 1_000_000_000,
+            Self::Hours => 60 * 60 * 1_000_000_000,
+            Self::Days => 24 * 60 * 60 * 1_000_000_000,
+        }
+    }
+
+    /// Returns the suffix of this unit, like `ms`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::TimeUnit;
+    /// assert_eq!(TimeUnit::Micros.suffix(), "µs");
+    /// ```
+    #[must_use]
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::Nanos => "ns",
+            Self::Micros => "µs",
+            Self::Millis => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "m",
+            Self::Hours => "h",
+            Self::Days => "d",
+        }
+    }
+}
+
+/// A type that formats a [`Duration`] in human-readable units, like `1d 1h 1m 1s`.
+///
+/// Each unit from the largest to the smallest unit is shown, skipping units that are zero. The
+/// smallest unit shows a fraction with the formatter's precision flag (0 by default, truncated).
+/// Durations shorter than one smallest unit are shown in the largest smaller unit that fits, like
+/// `450µs`. Defaults to [`TimeUnit::Days`] and [`TimeUnit::Seconds`]. The formatter's width, fill
+/// and alignment are supported, left aligned by default.
+///
+/// See [`IsoDuration`] for ISO 8601 output.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use display_as_debug::types::{HumanDuration, TimeUnit};
+/// assert_eq!(format!("{}", HumanDuration::new(Duration::from_secs(90_061))), "1d 1h 1m 1s");
+/// assert_eq!(format!("{:.2}", HumanDuration::new(Duration::from_millis(1_234))), "1.23s");
+/// assert_eq!(format!("{}", HumanDuration::new(Duration::from_micros(450))), "450µs");
+///
+/// let hours = HumanDuration::new(Duration::from_secs(90_061)).with_largest(TimeUnit::Hours);
+/// assert_eq!(format!("{:?}", hours.with_smallest(TimeUnit::Minutes)), "25h 1m");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the duration in human-readable units
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration {
+    /// The duration to format.
+    pub duration: Duration,
+    /// The largest unit to show, larger units are shown in this unit.
+    pub largest: TimeUnit,
+    /// The smallest unit to show, which includes any fraction.
+    pub smallest: TimeUnit,
+}
+
+impl HumanDuration {
+    /// Creates a new [`HumanDuration`], showing days to seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use display_as_debug::types::HumanDuration;
+    /// assert_eq!(format!("{}", HumanDuration::new(Duration::from_secs(3_600))), "1h");
+    /// ```
+    #[must_use]
+    pub const fn new(duration: Duration) -> Self {
+        Self { duration, largest: TimeUnit::Days, smallest: TimeUnit::Seconds }
+    }
+
+    /// Sets the largest unit to show.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use display_as_debug::types::{HumanDuration, TimeUnit};
+    /// let duration = HumanDuration::new(Duration::from_secs(7_200)).with_largest(TimeUnit::Minutes);
+    /// assert_eq!(format!("{duration}"), "120m");
+    /// ```
+    #[must_use]
+    pub const fn with_largest(self, largest: TimeUnit) -> Self {
+        Self { largest, ..self }
+    }
+
+    /// Sets the smallest unit to show.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::time::Duration;
+    /// # use display_as_debug::types::{HumanDuration, TimeUnit};
+    /// let duration = HumanDuration::new(Duration::from_millis(1_234)).with_smallest(TimeUnit::Millis);
+    /// assert_eq!(format!("{duration}"), "1s 234ms");
+    /// ```
+    #[must_use]
+    pub const fn with_smallest(self, smallest: TimeUnit) -> Self {
+        Self { smallest, ..self }
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        Self::new(duration)
+    }
+}
+
+impl HumanDuration {
+    /// Writes the duration in human-readable units, with `precision` fractional digits.
+    fn write_to(&self, w: &mut impl Write, precision: usize) -> Result {
+        let total = self.duration.as_nanos();
+        let smallest = self.smallest.min(self.largest);
+
+        if total < smallest.nanos() {
+            let unit = TimeUnit::DESCENDING.into_iter().find(|unit| *unit < smallest && total >= unit.nanos());
+            return fmt_unit(w, total, unit.unwrap_or(smallest), precision);
+        }
+
+        let mut remaining = total;
+        let mut separator = "";
+        for unit in TimeUnit::DESCENDING.into_iter().filter(|unit| (smallest..=self.largest).contains(unit)) {
+            if unit == smallest {
+                if remaining >= unit.nanos() || fraction(remaining, unit, precision) > 0 || separator.is_empty() {
+                    w.write_str(separator)?;
+                    fmt_unit(w, remaining, unit, precision)?;
+                }
+                break;
+            }
+
+            let whole = remaining / unit.nanos();
+            remaining %= unit.nanos();
+            if whole > 0 {
+                write!(w, "{separator}{whole}{}", unit.suffix())?;
+                separator = " ";
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut buf = FmtBuf::<MAX_HUMAN_LEN>::new();
+        self.write_to(&mut buf, f.precision().unwrap_or(0).min(MAX_PRECISION))?;
+        pad(f, buf.as_str())
+    }
+}
+
+impl Debug for HumanDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Returns the first `precision` digits of the fraction of `nanos` in `unit`, truncated.
+const fn fraction(nanos: u128, unit: TimeUnit, precision: usize) -> u128 {
+    // `precision` is at most `MAX_PRECISION`, so this cannot truncate
+    #[allow(clippy::cast_possible_truncation, reason = "precision is capped")]
+    let scale = 10_u128.pow(precision as u32);
+    nanos % unit.nanos() * scale / unit.nanos()
+}
+
+/// Formats `nanos` in `unit`, with `precision` fractional digits.
+fn fmt_unit(f: &mut impl Write, nanos: u128, unit: TimeUnit, precision: usize) -> Result {
+    let whole = nanos / unit.nanos();
+    match precision {
+        0 => write!(f, "{whole}{}", unit.suffix()),
+        _ => write!(f, "{whole}.{:0precision$}{}", fraction(nanos, unit, precision), unit.suffix()),
+    }
+}
+
+/// A type that formats a [`Duration`] as a compact ISO 8601 duration, like `PT1H1M`.
+///
+/// Only hours, minutes and seconds are used, since days are not always 24 hours long. Fractional
+/// seconds are shown without trailing zeros, or with the number of digits set by the formatter's
+/// precision flag (up to 9, truncated). The formatter's width, fill and alignment are supported,
+/// left aligned by default.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use display_as_debug::types::IsoDuration;
+/// assert_eq!(format!("{}", IsoDuration(Duration::from_secs(3_660))), "PT1H1M");
+/// assert_eq!(format!("{}", IsoDuration(Duration::from_millis(1_500))), "PT1.5S");
+/// assert_eq!(format!("{:.3}", IsoDuration(Duration::from_secs(90_061))), "PT25H1M1.000S");
+/// assert_eq!(format!("{:?}", IsoDuration(Duration::ZERO)), "PT0S");
+/// assert_eq!(format!("[{:>8}]", IsoDuration(Duration::from_secs(60))), "[    PT1M]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the duration as ISO 8601
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct IsoDuration(pub Duration);
+
+impl IsoDuration {
+    /// Writes the duration as ISO 8601, with `precision` fractional digits if set.
+    fn write_to(&self, w: &mut impl Write, precision: Option<usize>) -> Result {
+        let secs = self.0.as_secs();
+        let nanos = self.0.subsec_nanos();
+        let (hours, minutes, seconds) = (secs / 3_600, secs % 3_600 / 60, secs % 60);
+
+        w.write_str("PT")?;
+        if hours > 0 {
+            write!(w, "{hours}H")?;
+        }
+        if minutes > 0 {
+            write!(w, "{minutes}M")?;
+        }
+
+        if seconds > 0 || nanos > 0 || precision.is_some() || (hours == 0 && minutes == 0) {
+            write!(w, "{seconds}")?;
+            fmt_subsec(w, nanos, precision)?;
+            w.write_str("S")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for IsoDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut buf = FmtBuf::<MAX_ISO_LEN>::new();
+        self.write_to(&mut buf, f.precision())?;
+        pad(f, buf.as_str())
+    }
+}
+
+/// Formats the fraction of a second in `nanos`, including the leading `.`.
+///
+/// With a `precision`, exactly that many digits are written (up to 9, truncated). Otherwise the
+/// fraction is written without trailing zeros, or not at all if it is zero.
+pub(super) fn fmt_subsec(f: &mut impl Write, nanos: u32, precision: Option<usize>) -> Result {
+    match precision.map(|precision| precision.min(MAX_ISO_PRECISION)) {
+        Some(0) => Ok(()),
+        Some(precision) => {
+            // `precision` is at most `MAX_ISO_PRECISION`, so this cannot truncate
+            #[allow(clippy::cast_possible_truncation, reason = "precision is capped")]
+            let fraction = nanos / 10_u32.pow((MAX_ISO_PRECISION - precision) as u32);
+            write!(f, ".{fraction:0precision$}")
+        }
+        None if nanos > 0 => {
+            let mut digits = nanos;
+            let mut width = MAX_ISO_PRECISION;
+            while digits % 10 == 0 {
+                digits /= 10;
+                width -= 1;
+            }
+            write!(f, ".{digits:0width$}")
+        }
+        None => Ok(()),
+    }
+}
+
+impl Debug for IsoDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,157 @@
+//! Implementation of [`FnName`]
+
+use core::fmt::{Debug, Formatter, Result};
+use core::marker::PhantomData;
+
+use crate::types::{DisplayMode, Full, Short, TypeNameMode};
+
+/// The segment [`type_name`](core::any::type_name) uses for closures (and async blocks).
+const CLOSURE: &str = "{{closure}}";
+/// The cleaned up replacement for [`CLOSURE`].
+const CLOSURE_CLEAN: &str = "{closure}";
+/// Smart pointers that are peeled off to reach the wrapped function.
+const POINTERS: [&str; 4] = ["alloc::boxed::Box<", "alloc::rc::Rc<", "alloc::sync::Arc<", "core::pin::Pin<"];
+
+/// A type that formats as the cleaned up name of a function or closure type when used in [`Debug`]
+/// contexts.
+///
+/// Useful for structs holding callbacks (like `Box<dyn Fn()>` or `F: Fn()`), which otherwise cannot
+/// derive [`Debug`]. Compared to [`TypeName`](crate::types::TypeName), the name is cleaned up:
+/// - Smart pointers and references (`Box`, `Rc`, `Arc`, `Pin`, `&`) are peeled off.
+/// - Closures are shown as `{closure@path::to::enclosing_fn}`.
+/// - In [`Short`] mode, every path is shortened to its last segment, including generic arguments.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::{FnName, Full, Short};
+/// mod handlers {
+///     pub fn on_login(_: &str) {}
+/// }
+///
+/// assert_eq!(format!("{:?}", FnName::of::<Short>(&handlers::on_login)), "on_login");
+/// assert!(format!("{:?}", FnName::of::<Full>(&handlers::on_login)).ends_with("::handlers::on_login"));
+///
+/// fn make_adder() -> impl Fn(i32) -> i32 {
+///     |x| x + 1
+/// }
+/// assert_eq!(format!("{:?}", FnName::of::<Short>(&make_adder())), "{closure@make_adder}");
+///
+/// let boxed: Box<dyn Fn(String) + Send> = Box::new(|_| {});
+/// assert_eq!(format!("{:?}", FnName::of::<Short>(&boxed)), "dyn Fn(String) + Send");
+/// ```
+pub struct FnName<F: ?Sized, M: DisplayMode = Short>(PhantomData<(fn() -> F, M)>);
+
+impl<F: ?Sized> FnName<F> {
+    /// Creates a [`FnName`] for the type of the given function, using the given [`DisplayMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::{FnName, Full};
+    /// assert_eq!(format!("{:?}", FnName::of::<Full>(&u8::is_ascii)), "core::num::<impl u8>::is_ascii");
+    /// ```
+    #[must_use]
+    pub const fn of<M: DisplayMode>(_: &F) -> FnName<F, M> {
+        FnName(PhantomData)
+    }
+}
+
+impl<F: ?Sized, M: DisplayMode> FnName<F, M> {
+    /// A constant instance showing the full function name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::FnName;
+    /// assert_eq!(format!("{:?}", FnName::<fn(String)>::FULL), "fn(alloc::string::String)");
+    /// ```
+    pub const FULL: FnName<F, Full> = FnName(PhantomData);
+
+    /// A constant instance showing the short function name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::FnName;
+    /// assert_eq!(format!("{:?}", FnName::<fn(String)>::SHORT), "fn(String)");
+    /// ```
+    pub const SHORT: FnName<F, Short> = FnName(PhantomData);
+}
+
+impl<F: ?Sized, M: DisplayMode> Clone for FnName<F, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: ?Sized, M: DisplayMode> Copy for FnName<F, M> {}
+
+impl<F: ?Sized, M: DisplayMode> Debug for FnName<F, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_fn_name(core::any::type_name::<F>(), M::MODE, f)
+    }
+}
+
+/// Formats a function type name cleaned up according to `mode`.
+fn fmt_fn_name(name: &str, mode: TypeNameMode, f: &mut Formatter<'_>) -> Result {
+    let mut path = peel(name);
+    let mut closure = false;
+    while let Some(parent) = path.strip_suffix(CLOSURE).and_then(|parent| parent.strip_suffix("::")) {
+        path = parent;
+        closure = true;
+    }
+
+    if !closure {
+        return fmt_path(path, mode, f);
+    }
+
+    f.write_str("{closure@")?;
+    fmt_path(path, mode, f)?;
+    f.write_str("}")
+}
+
+/// Peels references and smart pointers off a type name.
+fn peel(mut name: &str) -> &str {
+    loop {
+        if let Some(inner) = name.strip_prefix("&mut ").or_else(|| name.strip_prefix('&')) {
+            name = inner;
+            continue;
+        }
+        match POINTERS.iter().find_map(|pointer| name.strip_prefix(pointer)?.strip_suffix('>')) {
+            Some(inner) => name = inner,
+            None => return name,
+        }
+    }
+}
+
+/// Formats a path, shortening every path in it to its last segment in [`TypeNameMode::Short`].
+fn fmt_path(path: &str, mode: TypeNameMode, f: &mut Formatter<'_>) -> Result {
+    match mode {
+        TypeNameMode::Full => fmt_closures(path, f),
+        TypeNameMode::Short => path.split_inclusive(is_delimiter).try_for_each(|token| {
+            let (segment, delimiter) = token.split_at(token.trim_end_matches(is_delimiter).len());
+            let segment = match segment.rfind("::") {
+                Some(index) if index > 0 => &segment[index + 2..],
+                _ => segment,
+            };
+            fmt_closures(segment, f)?;
+            f.write_str(delimiter)
+        }),
+    }
+}
+
+/// Formats a path, replacing any `{{closure}}` segments with `{closure}`.
+fn fmt_closures(path: &str, f: &mut Formatter<'_>) -> Result {
+    let mut parts = path.split(CLOSURE);
+    f.write_str(parts.next().unwrap_or_default())?;
+    parts.try_for_each(|part| {
+        f.write_str(CLOSURE_CLEAN)?;
+        f.write_str(part)
+    })
+}
+
+/// Whether `c` separates the paths within a type name.
+const fn is_delimiter(c: char) -> bool {
+    matches!(c, '<' | '>' | '(' | ')' | '[' | ']' | ',' | ';' | ' ' | '&' | '*')
+}
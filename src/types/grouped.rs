@@ -0,0 +1,107 @@
+//! Implementation of [`Grouped`]
+
+use core::fmt::{Debug, Display, Error, Formatter, Result};
+
+use crate::types::Integer;
+
+/// The longest output: 39 digits of a [`u128`], with a 4 byte separator between each of the 13
+/// groups.
+const MAX_LEN: usize = 39 + 12 * 4;
+
+/// A type that formats an integer with its digits grouped in threes, like `1_234_567`, for
+/// readable counts and sizes.
+///
+/// The separator defaults to `_`, matching Rust integer literals, and can be changed with
+/// [`Grouped::with_separator`], such as to `,` for `1,234,567`. The formatter's width, fill,
+/// alignment and sign flags are supported. No allocation is performed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::Grouped;
+/// assert_eq!(format!("{}", Grouped::new(1_234_567)), "1_234_567");
+/// assert_eq!(format!("{:?}", Grouped::new(-1_234_567_i64).with_separator(',')), "-1,234,567");
+/// assert_eq!(format!("{:>8}", Grouped::new(1_000_u16)), "   1_000");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the integer with grouped digits
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grouped<N> {
+    /// The integer to format.
+    pub value: N,
+    /// The separator written between groups of digits.
+    pub separator: char,
+}
+
+impl<N> Grouped<N> {
+    /// Creates a new [`Grouped`], separating groups with `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Grouped;
+    /// assert_eq!(format!("{}", Grouped::new(999_u16)), "999");
+    /// ```
+    #[must_use]
+    pub const fn new(value: N) -> Self {
+        Self { value, separator: '_' }
+    }
+
+    /// Sets the separator written between groups of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Grouped;
+    /// assert_eq!(format!("{}", Grouped::new(1_234_567).with_separator(' ')), "1 234 567");
+    /// ```
+    #[must_use]
+    pub fn with_separator(self, separator: char) -> Self {
+        Self { separator, ..self }
+    }
+}
+
+impl<N: Integer> From<N> for Grouped<N> {
+    fn from(value: N) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<N: Integer> Display for Grouped<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (negative, mut magnitude) = self.value.split_sign();
+        let mut separator = [0; 4];
+        let separator = self.separator.encode_utf8(&mut separator).as_bytes();
+
+        // digits are written from the end of the buffer, least significant first
+        let mut buf = [0; MAX_LEN];
+        let mut start = MAX_LEN;
+        for digits in 0.. {
+            if digits > 0 && digits % 3 == 0 {
+                start -= separator.len();
+                buf[start..start + separator.len()].copy_from_slice(separator);
+            }
+            start -= 1;
+            // the remainder is a single digit, so this cannot truncate
+            #[allow(clippy::cast_possible_truncation, reason = "remainder is a single digit")]
+            let digit = (magnitude % 10) as u8;
+            buf[start] = b'0' + digit;
+            magnitude /= 10;
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        let text = core::str::from_utf8(&buf[start..]).map_err(|_| Error)?;
+        f.pad_integral(!negative, "", text)
+    }
+}
+
+impl<N: Integer> Debug for Grouped<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,52 @@
+//! Implementation of [`Integer`]
+
+/// Sealed trait for the primitive integer types, used by [`Grouped`](crate::types::Grouped) and
+/// [`Ordinal`](crate::types::Ordinal).
+#[sealed::sealed]
+pub trait Integer: Copy {
+    /// Returns whether the value is negative, and its absolute value.
+    fn split_sign(self) -> (bool, u128);
+}
+
+/// Implements [`Integer`] for unsigned types.
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {$(
+        #[sealed::sealed]
+        impl Integer for $ty {
+            fn split_sign(self) -> (bool, u128) {
+                (false, u128::from(self))
+            }
+        }
+    )*};
+}
+
+/// Implements [`Integer`] for signed types.
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {$(
+        #[sealed::sealed]
+        impl Integer for $ty {
+            fn split_sign(self) -> (bool, u128) {
+                (self < 0, u128::from(self.unsigned_abs()))
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128);
+impl_signed!(i8, i16, i32, i64, i128);
+
+#[sealed::sealed]
+impl Integer for usize {
+    fn split_sign(self) -> (bool, u128) {
+        // `usize` is at most 128 bits on all supported targets
+        (false, self as u128)
+    }
+}
+
+#[sealed::sealed]
+impl Integer for isize {
+    fn split_sign(self) -> (bool, u128) {
+        // `usize` is at most 128 bits on all supported targets
+        (self < 0, self.unsigned_abs() as u128)
+    }
+}
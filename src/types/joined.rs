@@ -0,0 +1,97 @@
+//! Implementation of [`Joined`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::fmt::DebugListExt;
+
+/// A type that formats the items of an iterator joined by a separator, like `a, b, c`.
+///
+/// An optional last separator can be used between the final two items, like `a, b and c`. The
+/// iterator must be [`Clone`] (such as a slice or collection reference), so that it can be formatted
+/// more than once.
+///
+/// Formatting flags are forwarded to each item, so `{:.1}` formats each item with a precision of 1.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::Joined;
+/// let items = ["a", "b", "c"];
+/// assert_eq!(format!("{}", Joined::new(&items, ", ")), "a, b, c");
+/// assert_eq!(format!("{}", Joined::new(&items, ", ").with_last(" and ")), "a, b and c");
+/// assert_eq!(format!("{:.1}", Joined::new([1.0, 2.5], " | ")), "1.0 | 2.5");
+/// assert_eq!(format!("{:?}", Joined::new(&items, ", ")), "[a, b, c]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the items using their [`Display`] implementations, joined by the separator
+/// - **[`Debug`]**: Formats the items as a list, using their [`Display`] implementations
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Joined<I, S = &'static str> {
+    /// The items to join.
+    pub iter: I,
+    /// The separator between items.
+    pub separator: S,
+    /// The separator between the final two items, if different from [`Joined::separator`].
+    pub last: Option<S>,
+}
+
+impl<I, S> Joined<I, S> {
+    /// Creates a new [`Joined`] with the given items and separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Joined;
+    /// assert_eq!(format!("{}", Joined::new(1..4, "-")), "1-2-3");
+    /// ```
+    #[must_use]
+    pub const fn new(iter: I, separator: S) -> Self {
+        Self { iter, separator, last: None }
+    }
+
+    /// Sets the separator used between the final two items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Joined;
+    /// let joined = Joined::new(["x", "y", "z"], ", ").with_last(", or ");
+    /// assert_eq!(format!("{joined}"), "x, y, or z");
+    /// ```
+    #[must_use]
+    pub fn with_last(self, last: S) -> Self {
+        Self { last: Some(last), ..self }
+    }
+}
+
+impl<I, S> Display for Joined<I, S>
+where
+    I: IntoIterator<Item: Display> + Clone,
+    S: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut iter = self.iter.clone().into_iter().peekable();
+        if let Some(first) = iter.next() {
+            Display::fmt(&first, f)?;
+        }
+        while let Some(item) = iter.next() {
+            match (&self.last, iter.peek()) {
+                (Some(last), None) => write!(f, "{last}")?,
+                _ => write!(f, "{}", self.separator)?,
+            }
+            Display::fmt(&item, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, S> Debug for Joined<I, S>
+where
+    I: IntoIterator<Item: Display> + Clone,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries_display(self.iter.clone()).finish()
+    }
+}
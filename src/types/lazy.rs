@@ -0,0 +1,169 @@
+//! Implementation of [`LazyDisplay`] and [`LazyDebug`], and the [`display!`](crate::display) and [`debug!`](crate::debug) macros.
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::wrap::Quoted;
+
+/// A value that lazily formats using a closure, as produced by the [`display!`](crate::display) macro.
+///
+/// Behaves like a [`DisplayAsDebug`](crate::wrap::DisplayAsDebug) wrapped [`String`] built by
+/// [`format!`], but without allocating. Both [`Display`] and [`Debug`] write the formatted text.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::LazyDisplay;
+/// let (host, port) = ("localhost", 8080);
+/// let addr = LazyDisplay::new(|f| write!(f, "{host}:{port}"));
+/// assert_eq!(format!("{addr}"), "localhost:8080");
+/// assert_eq!(format!("{addr:?}"), "localhost:8080");
+/// ```
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+#[derive(Copy, Clone)]
+pub struct LazyDisplay<F>(pub F);
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> LazyDisplay<F> {
+    /// Creates a new [`LazyDisplay`] that formats using `f`.
+    #[must_use]
+    pub const fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> Display for LazyDisplay<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.0)(f)
+    }
+}
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> Debug for LazyDisplay<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.0)(f)
+    }
+}
+
+/// A value that lazily formats using a closure, as produced by the [`debug!`](crate::debug) macro.
+///
+/// Behaves like a [`String`] built by [`format!`], but without allocating. [`Display`] writes the
+/// formatted text, and [`Debug`] writes it escaped and quoted, like [`Quoted`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::LazyDebug;
+/// let name = "a\tb";
+/// let value = LazyDebug::new(|f| write!(f, "name={name}"));
+/// assert_eq!(format!("{value}"), "name=a\tb");
+/// assert_eq!(format!("{value:?}"), r#""name=a\tb""#);
+/// ```
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+#[derive(Copy, Clone)]
+pub struct LazyDebug<F>(pub F);
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> LazyDebug<F> {
+    /// Creates a new [`LazyDebug`] that formats using `f`.
+    #[must_use]
+    pub const fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> Display for LazyDebug<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.0)(f)
+    }
+}
+
+impl<F: Fn(&mut Formatter<'_>) -> Result> Debug for LazyDebug<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&Quoted(self), f)
+    }
+}
+
+/// Creates a [`LazyDisplay`] from a format string and arguments,
+/// without allocating.
+///
+/// Unlike [`format_args!`], the result can be stored, and outlive the statement that created it.
+/// Arguments are captured by reference, or by move if the arguments are prefixed with `move`.
+///
+/// Both [`Display`] and [`Debug`] write the formatted text,
+/// making the result usable with
+/// [`DebugStructExt::field_display`](crate::fmt::DebugStructExt::field_display),
+/// [`DebugListExt::entries_display`](crate::fmt::DebugListExt::entries_display), and the like.
+///
+/// # Examples
+///
+/// ```rust
+/// use display_as_debug::display;
+/// use display_as_debug::fmt::DebugListExt;
+/// use std::fmt::{Debug, Formatter};
+///
+/// struct Endpoints(Vec<(&'static str, u16)>);
+///
+/// impl Debug for Endpoints {
+///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+///         f.debug_list().entries_display(self.0.iter().map(|(host, port)| display!(move "{host}:{port}"))).finish()
+///     }
+/// }
+///
+/// let endpoints = Endpoints(vec![("localhost", 80), ("example.com", 443)]);
+/// assert_eq!(format!("{endpoints:?}"), "[localhost:80, example.com:443]");
+///
+/// fn describe(id: u32) -> impl std::fmt::Display {
+///     display!(move "item #{id}")
+/// }
+/// assert_eq!(describe(7).to_string(), "item #7");
+/// ```
+#[macro_export]
+macro_rules! display {
+    (move $($arg:tt)+) => {
+        $crate::types::LazyDisplay::new(move |f: &mut ::core::fmt::Formatter<'_>| ::core::write!(f, $($arg)+))
+    };
+    ($($arg:tt)+) => {
+        $crate::types::LazyDisplay::new(|f: &mut ::core::fmt::Formatter<'_>| ::core::write!(f, $($arg)+))
+    };
+}
+
+/// Creates a [`LazyDebug`] from a format string and arguments, without
+/// allocating.
+///
+/// The result behaves like the [`String`] that [`format!`] would produce: [`Display`] writes the
+/// formatted text, and [`Debug`] writes it escaped and quoted. Arguments are captured by reference,
+/// or by move if the arguments are prefixed with `move`.
+///
+/// # Examples
+///
+/// ```rust
+/// use display_as_debug::debug;
+/// use display_as_debug::fmt::DebugStructExt;
+/// use std::fmt::{Debug, Formatter};
+///
+/// struct Request { method: &'static str, path: &'static str }
+///
+/// impl Debug for Request {
+///     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+///         f.debug_struct("Request").field("line", &debug!("{} {}", self.method, self.path)).finish()
+///     }
+/// }
+///
+/// let request = Request { method: "GET", path: "/" };
+/// assert_eq!(format!("{request:?}"), r#"Request { line: "GET /" }"#);
+/// ```
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+/// [`Display`]: core::fmt::Display
+/// [`Debug`]: core::fmt::Debug
+#[macro_export]
+macro_rules! debug {
+    (move $($arg:tt)+) => {
+        $crate::types::LazyDebug::new(move |f: &mut ::core::fmt::Formatter<'_>| ::core::write!(f, $($arg)+))
+    };
+    ($($arg:tt)+) => {
+        $crate::types::LazyDebug::new(|f: &mut ::core::fmt::Formatter<'_>| ::core::write!(f, $($arg)+))
+    };
+}
@@ -0,0 +1,47 @@
+//! Implementation of [`MaskedEmail`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+/// The text written in place of the masked characters of the local part.
+const MASK: &str = "***";
+
+/// A type that formats an email address with its local part masked, like `j***@example.com`.
+///
+/// Only the first character of the local part (before the last `@`) is kept, the domain is kept
+/// as-is. Text without an `@` is masked entirely except for its first character. No allocation is
+/// performed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::MaskedEmail;
+/// assert_eq!(format!("{}", MaskedEmail("jane.doe@example.com")), "j***@example.com");
+/// assert_eq!(format!("{:?}", MaskedEmail(String::from("éva@example.org"))), "é***@example.org");
+/// assert_eq!(format!("{}", MaskedEmail("not-an-email")), "n***");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the masked email address
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct MaskedEmail<T>(pub T);
+
+impl<T: AsRef<str>> Display for MaskedEmail<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let email = self.0.as_ref();
+        let (local, domain) = email.rsplit_once('@').map_or((email, None), |(local, domain)| (local, Some(domain)));
+
+        let first = local.chars().next().map_or("", |first| &local[..first.len_utf8()]);
+        write!(f, "{first}{MASK}")?;
+        domain.map_or(Ok(()), |domain| write!(f, "@{domain}"))
+    }
+}
+
+impl<T: AsRef<str>> Debug for MaskedEmail<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,194 @@
+//! Implementation of [`MaskedIp`]
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::types::pad::pad;
+use crate::write::FmtBuf;
+
+/// The default IPv4 prefix length, which keeps the network of a typical subnet.
+const DEFAULT_V4_PREFIX: u8 = 24;
+
+/// The default IPv6 prefix length, which keeps the network of a typical site.
+const DEFAULT_V6_PREFIX: u8 = 48;
+
+/// The longest output: a bracketed 39 character IPv6 address with a `/128` prefix length and a
+/// 5 digit port.
+const MAX_LEN: usize = 1 + 39 + 4 + 2 + 5;
+
+/// Sealed trait for the [`core::net`] address types that can be masked by a [`MaskedIp`].
+#[sealed::sealed]
+pub trait IpAddress: Copy {
+    /// Writes the address, keeping only the first `v4_prefix` or `v6_prefix` bits of the IP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer fails.
+    fn fmt_masked(&self, v4_prefix: u8, v6_prefix: u8, f: &mut impl Write) -> Result;
+}
+
+#[sealed::sealed]
+impl IpAddress for Ipv4Addr {
+    fn fmt_masked(&self, v4_prefix: u8, _: u8, f: &mut impl Write) -> Result {
+        let prefix = u32::from(v4_prefix.min(32));
+        let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+        let octets = (self.to_bits() & mask).to_be_bytes();
+        octets.into_iter().zip([0, 8, 16, 24]).try_for_each(|(octet, start)| {
+            let separator = if start == 0 { "" } else { "." };
+            if start < prefix { write!(f, "{separator}{octet}") } else { write!(f, "{separator}x") }
+        })
+    }
+}
+
+#[sealed::sealed]
+impl IpAddress for Ipv6Addr {
+    fn fmt_masked(&self, _: u8, v6_prefix: u8, f: &mut impl Write) -> Result {
+        let prefix = u32::from(v6_prefix.min(128));
+        let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+        write!(f, "{}/{prefix}", Self::from_bits(self.to_bits() & mask))
+    }
+}
+
+#[sealed::sealed]
+impl IpAddress for IpAddr {
+    fn fmt_masked(&self, v4_prefix: u8, v6_prefix: u8, f: &mut impl Write) -> Result {
+        match self {
+            Self::V4(addr) => addr.fmt_masked(v4_prefix, v6_prefix, f),
+            Self::V6(addr) => addr.fmt_masked(v4_prefix, v6_prefix, f),
+        }
+    }
+}
+
+#[sealed::sealed]
+impl IpAddress for SocketAddrV4 {
+    fn fmt_masked(&self, v4_prefix: u8, v6_prefix: u8, f: &mut impl Write) -> Result {
+        self.ip().fmt_masked(v4_prefix, v6_prefix, f)?;
+        write!(f, ":{}", self.port())
+    }
+}
+
+#[sealed::sealed]
+impl IpAddress for SocketAddrV6 {
+    fn fmt_masked(&self, v4_prefix: u8, v6_prefix: u8, f: &mut impl Write) -> Result {
+        f.write_str("[")?;
+        self.ip().fmt_masked(v4_prefix, v6_prefix, f)?;
+        write!(f, "]:{}", self.port())
+    }
+}
+
+#[sealed::sealed]
+impl IpAddress for SocketAddr {
+    fn fmt_masked(&self, v4_prefix: u8, v6_prefix: u8, f: &mut impl Write) -> Result {
+        match self {
+            Self::V4(addr) => addr.fmt_masked(v4_prefix, v6_prefix, f),
+            Self::V6(addr) => addr.fmt_masked(v4_prefix, v6_prefix, f),
+        }
+    }
+}
+
+/// A type that formats an IP or socket address with its host bits masked, so client addresses can
+/// be logged without identifying the client.
+///
+/// Only the first `v4_prefix` or `v6_prefix` bits of the IP are kept, 24 and 48 by default.
+/// IPv4 octets past the prefix are shown as `x`, like `203.0.113.x`. IPv6 host bits are zeroed and
+/// the prefix length is appended, like `2001:db8::/48`. Ports of socket addresses are kept. The
+/// formatter's width, fill and alignment are supported, left aligned by default.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+/// # use display_as_debug::types::MaskedIp;
+/// let v4 = Ipv4Addr::new(203, 0, 113, 42);
+/// assert_eq!(format!("{}", MaskedIp::new(v4)), "203.0.113.x");
+/// assert_eq!(format!("{:?}", MaskedIp::new(v4).with_v4_prefix(20)), "203.0.112.x");
+///
+/// let v6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0x1, 0x2, 0, 0, 0, 0x1));
+/// assert_eq!(format!("{}", MaskedIp::new(v6)), "2001:db8:1::/48");
+/// assert_eq!(format!("{}", MaskedIp::new(v6).with_v6_prefix(32)), "2001:db8::/32");
+///
+/// let socket = SocketAddr::new(IpAddr::V4(v4), 8080);
+/// assert_eq!(format!("{}", MaskedIp::new(socket)), "203.0.113.x:8080");
+/// assert_eq!(format!("[{:>13}]", MaskedIp::new(v4)), "[  203.0.113.x]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the masked address
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaskedIp<A> {
+    /// The address to mask.
+    pub addr: A,
+    /// The number of leading bits of an IPv4 address to keep.
+    pub v4_prefix: u8,
+    /// The number of leading bits of an IPv6 address to keep.
+    pub v6_prefix: u8,
+}
+
+impl<A: IpAddress> MaskedIp<A> {
+    /// Creates a new [`MaskedIp`], keeping the first 24 bits of an IPv4 address, or the first 48
+    /// bits of an IPv6 address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::net::Ipv4Addr;
+    /// # use display_as_debug::types::MaskedIp;
+    /// assert_eq!(format!("{}", MaskedIp::new(Ipv4Addr::LOCALHOST)), "127.0.0.x");
+    /// ```
+    #[must_use]
+    pub const fn new(addr: A) -> Self {
+        Self { addr, v4_prefix: DEFAULT_V4_PREFIX, v6_prefix: DEFAULT_V6_PREFIX }
+    }
+
+    /// Sets the number of leading bits of an IPv4 address to keep, up to 32.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::net::Ipv4Addr;
+    /// # use display_as_debug::types::MaskedIp;
+    /// let masked = MaskedIp::new(Ipv4Addr::new(10, 1, 2, 3)).with_v4_prefix(8);
+    /// assert_eq!(format!("{masked}"), "10.x.x.x");
+    /// ```
+    #[must_use]
+    pub const fn with_v4_prefix(self, v4_prefix: u8) -> Self {
+        Self { v4_prefix, ..self }
+    }
+
+    /// Sets the number of leading bits of an IPv6 address to keep, up to 128.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::net::Ipv6Addr;
+    /// # use display_as_debug::types::MaskedIp;
+    /// let masked = MaskedIp::new(Ipv6Addr::new(0x2001, 0xdb8, 1, 2, 3, 4, 5, 6)).with_v6_prefix(64);
+    /// assert_eq!(format!("{masked}"), "2001:db8:1:2::/64");
+    /// ```
+    #[must_use]
+    pub const fn with_v6_prefix(self, v6_prefix: u8) -> Self {
+        Self { v6_prefix, ..self }
+    }
+}
+
+impl<A: IpAddress> From<A> for MaskedIp<A> {
+    fn from(addr: A) -> Self {
+        Self::new(addr)
+    }
+}
+
+impl<A: IpAddress> Display for MaskedIp<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut buf = FmtBuf::<MAX_LEN>::new();
+        self.addr.fmt_masked(self.v4_prefix, self.v6_prefix, &mut buf)?;
+        pad(f, buf.as_str())
+    }
+}
+
+impl<A: IpAddress> Debug for MaskedIp<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
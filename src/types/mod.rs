@@ -1,16 +1,55 @@
 //! Types that implement [`Debug`](core::fmt::Debug) for specialized formatting.
 
+mod addr;
+mod base64;
+mod byte_size;
+mod byte_str;
+mod count;
+mod display;
+mod duration;
+mod fn_name;
+mod grouped;
+mod integer;
+mod joined;
+mod lazy;
+mod masked_email;
+mod masked_ip;
 mod opaque;
+mod ordinal;
+mod pad;
+mod ratio;
+mod redacted_url;
 mod test_value;
 mod type_name;
+mod unix_time;
 
 pub use crate::wrap::Opaque;
-pub use opaque::{OpaqueList, OpaqueMap, OpaqueSet};
+pub use addr::Addr;
+#[cfg(feature = "alloc")]
+pub use addr::SharedAddr;
+pub use base64::{Base64, Base64Alphabet, StandardAlphabet, UrlSafeAlphabet};
+pub use byte_size::{ByteSize, DecimalByteSize};
+pub use byte_str::ByteStr;
+pub use count::Count;
+pub use display::{DisplayList, DisplayMap, DisplaySet};
+pub use duration::{HumanDuration, IsoDuration, TimeUnit};
+pub use fn_name::FnName;
+pub use grouped::Grouped;
+pub use integer::Integer;
+pub use joined::Joined;
+pub use lazy::{LazyDebug, LazyDisplay};
+pub use masked_email::MaskedEmail;
+pub use masked_ip::{IpAddress, MaskedIp};
+pub use opaque::{OpaqueList, OpaqueMap, OpaqueSet, OpaqueValues};
+pub use ordinal::Ordinal;
+pub use ratio::{Percent, Ratio};
+pub use redacted_url::RedactedUrl;
 pub use test_value::TestValue;
 pub use type_name::{
-    DisplayMode, Full, Short, TypeName, TypeNameList, TypeNameMap, TypeNameMarker, TypeNameOption, TypeNameResult,
-    TypeNameSet,
+    DisplayMode, Full, FullyTypeNameResult, FullyTypeNameResultMarker, Short, TypeName, TypeNameList, TypeNameMap,
+    TypeNameMarker, TypeNameMode, TypeNameOption, TypeNameResult, TypeNameSet, TypeNameValues,
 };
+pub use unix_time::UnixTime;
 
 /// An obscure marker value that formats as `..` when used in [`Debug`](core::fmt::Debug) or [`Display`](core::fmt::Display).
 ///
@@ -1,12 +1,15 @@
 use core::fmt::{Debug, Display, Formatter, Result};
 
-use crate::types::OPAQUE;
+use crate::types::{Count, Grouped, OPAQUE};
 
 /// A type that formats as `[..: N]` when used with [`Debug`].
 ///
 /// Useful for summarizing large collections by showing only their length, or for hiding sensitive
 /// details.
 ///
+/// The count is a [`usize`], or a [`Grouped`] one from [`OpaqueList::grouped`], as set by the sealed
+/// [`Count`] trait.
+///
 /// # Example
 ///
 /// ```
@@ -15,7 +18,7 @@ use crate::types::OPAQUE;
 /// assert_eq!(format!("{}", OpaqueList(100)), "[..: 100]");
 /// ```
 #[derive(Clone, Copy, PartialEq, Eq)]
-pub struct OpaqueList(pub usize);
+pub struct OpaqueList<C: Count = usize>(pub C);
 
 impl OpaqueList {
     /// Creates a new [`OpaqueList`] from an iterator with an exact size.
@@ -31,15 +34,28 @@ impl OpaqueList {
     pub fn of<I: IntoIterator<IntoIter: ExactSizeIterator>>(iter: I) -> Self {
         Self(iter.into_iter().len())
     }
+
+    /// Groups the digits of the count, like `1_234_567`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use display_as_debug::types::OpaqueList;
+    /// assert_eq!(format!("{:?}", OpaqueList(1_234_567).grouped()), "[..: 1_234_567]");
+    /// ```
+    #[must_use]
+    pub const fn grouped(self) -> OpaqueList<Grouped<usize>> {
+        OpaqueList(Grouped::new(self.0))
+    }
 }
 
-impl Debug for OpaqueList {
+impl<C: Count> Debug for OpaqueList<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "[{}: {}]", OPAQUE, self.0)
     }
 }
 
-impl Display for OpaqueList {
+impl<C: Count> Display for OpaqueList<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         Debug::fmt(self, f)
     }
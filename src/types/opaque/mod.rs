@@ -2,6 +2,8 @@
 
 mod list;
 mod set;
+mod values;
 
 pub use list::OpaqueList;
 pub use set::{OpaqueMap, OpaqueSet};
+pub use values::OpaqueValues;
@@ -1,12 +1,15 @@
 use core::fmt::{Debug, Display, Formatter, Result};
 
-use crate::types::OPAQUE;
+use crate::types::{Count, Grouped, OPAQUE};
 
 /// A type that formats as `{..: N}` when used with [`Debug`].
 ///
 /// Useful for summarizing large sets or maps by showing only their length, or for hiding sensitive
 /// details. Also available as the [`OpaqueMap`] alias.
 ///
+/// The count is a [`usize`], or a [`Grouped`] one from [`OpaqueSet::grouped`], as set by the sealed
+/// [`Count`] trait.
+///
 /// # Example
 ///
 /// ```
@@ -15,7 +18,7 @@ use crate::types::OPAQUE;
 /// assert_eq!(format!("{}", OpaqueSet(100)), "{..: 100}");
 /// ```
 #[derive(Clone, Copy, PartialEq, Eq)]
-pub struct OpaqueSet(pub usize);
+pub struct OpaqueSet<C: Count = usize>(pub C);
 
 /// Type alias for [`OpaqueSet`], useful when representing a map.
 ///
@@ -44,15 +47,28 @@ impl OpaqueSet {
     pub fn of<I: IntoIterator<IntoIter: ExactSizeIterator>>(iter: I) -> Self {
         Self(iter.into_iter().len())
     }
+
+    /// Groups the digits of the count, like `1_234_567`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use display_as_debug::types::OpaqueSet;
+    /// assert_eq!(format!("{:?}", OpaqueSet(1_234_567).grouped()), "{..: 1_234_567}");
+    /// ```
+    #[must_use]
+    pub const fn grouped(self) -> OpaqueSet<Grouped<usize>> {
+        OpaqueSet(Grouped::new(self.0))
+    }
 }
 
-impl Debug for OpaqueSet {
+impl<C: Count> Debug for OpaqueSet<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{{{}: {}}}", OPAQUE, self.0)
     }
 }
 
-impl Display for OpaqueSet {
+impl<C: Count> Display for OpaqueSet<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         Debug::fmt(self, f)
     }
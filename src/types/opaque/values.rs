@@ -0,0 +1,49 @@
+//! Implementation of [`OpaqueValues`]
+
+use core::fmt::{Debug, Formatter, Result};
+
+use crate::fmt::DebugMapExt;
+
+/// A borrowed map that formats its values as `..` when used with [`Debug`], showing only the keys.
+///
+/// Saves writing a manual [`Debug`] implementation using [`DebugMapExt::entries_opaque`].
+/// Declaring a field as an [`OpaqueValues`] allows `#[derive(Debug)]` to be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::OpaqueValues;
+/// # use std::collections::BTreeMap;
+/// #[derive(Debug)]
+/// struct Config<'a> {
+///     secrets: OpaqueValues<'a, BTreeMap<&'static str, &'static str>>,
+/// }
+///
+/// let secrets = BTreeMap::from([("api_key", "hunter2"), ("token", "abc123")]);
+/// let config = Config { secrets: OpaqueValues(&secrets) };
+/// assert_eq!(format!("{config:?}"), r#"Config { secrets: {"api_key": .., "token": ..} }"#);
+/// ```
+pub struct OpaqueValues<'a, C: ?Sized>(pub &'a C);
+
+impl<C: ?Sized> Clone for OpaqueValues<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for OpaqueValues<'_, C> {}
+
+impl<'a, C: ?Sized> From<&'a C> for OpaqueValues<'a, C> {
+    fn from(map: &'a C) -> Self {
+        Self(map)
+    }
+}
+
+impl<'a, C: ?Sized, K: Debug, V> Debug for OpaqueValues<'a, C>
+where
+    &'a C: IntoIterator<Item = (K, V)>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries_opaque(self.0).finish()
+    }
+}
@@ -0,0 +1,62 @@
+//! Implementation of [`Ordinal`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+use crate::types::Integer;
+
+/// A type that formats an integer as an English ordinal, like `1st`, `2nd`, `3rd` or `11th`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::Ordinal;
+/// assert_eq!(format!("{}", Ordinal(3)), "3rd");
+/// assert_eq!(format!("{}", Ordinal(12_u8)), "12th");
+/// assert_eq!(format!("{:?}", Ordinal(-21)), "-21st");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the integer with its ordinal suffix
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct Ordinal<N>(pub N);
+
+impl<N: Integer> Ordinal<N> {
+    /// Returns the ordinal suffix of the integer, like `st` or `th`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Ordinal;
+    /// assert_eq!(Ordinal(102).suffix(), "nd");
+    /// assert_eq!(Ordinal(113).suffix(), "th");
+    /// ```
+    #[must_use]
+    pub fn suffix(&self) -> &'static str {
+        let (_, magnitude) = self.0.split_sign();
+        match (magnitude % 100, magnitude % 10) {
+            (11..=13, _) => "th",
+            (_, 1) => "st",
+            (_, 2) => "nd",
+            (_, 3) => "rd",
+            _ => "th",
+        }
+    }
+}
+
+impl<N: Integer> Display for Ordinal<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (negative, magnitude) = self.0.split_sign();
+        let sign = if negative { "-" } else { "" };
+        write!(f, "{sign}{magnitude}{}", self.suffix())
+    }
+}
+
+impl<N: Integer> Debug for Ordinal<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,21 @@
+//! Padding for types whose output is formatted into a buffer first.
+
+use core::fmt::{Alignment, Formatter, Result, Write};
+
+/// Writes `text`, padded to the formatter's width with its fill and alignment, left aligned by
+/// default.
+///
+/// Unlike [`Formatter::pad`], the text is never truncated to the formatter's precision, which the
+/// types using this give their own meaning, such as the number of fractional digits.
+pub(super) fn pad(f: &mut Formatter<'_>, text: &str) -> Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    f.write_str(text)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
@@ -0,0 +1,146 @@
+//! Implementation of [`Percent`] and [`Ratio`]
+
+use core::fmt::{Debug, Display, Formatter, Result};
+
+/// The largest supported precision, so that the scaled percentage fits in a [`u128`].
+const MAX_PRECISION: usize = 16;
+
+/// A type that formats a part of a whole as a percentage, like `42.9%`.
+///
+/// The percentage is rounded to the number of decimal places set by the formatter's precision flag
+/// (1 by default, up to 16), using integer math. Parts larger than the whole are shown over
+/// `100%`. A whole of zero is shown as `NaN%`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::Percent;
+/// assert_eq!(format!("{}", Percent::new(21, 50)), "42.0%");
+/// assert_eq!(format!("{:.2}", Percent::new(3, 7)), "42.86%");
+/// assert_eq!(format!("{:.0?}", Percent::new(3, 2)), "150%");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the part as a percentage of the whole
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Percent {
+    /// The part of the whole.
+    pub part: u64,
+    /// The whole, which is `100%`.
+    pub whole: u64,
+}
+
+impl Percent {
+    /// Creates a new [`Percent`] of `part` out of `whole`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Percent;
+    /// assert_eq!(format!("{}", Percent::new(1, 3)), "33.3%");
+    /// ```
+    #[must_use]
+    pub const fn new(part: u64, whole: u64) -> Self {
+        Self { part, whole }
+    }
+}
+
+impl Display for Percent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.whole == 0 {
+            return f.write_str("NaN%");
+        }
+
+        let precision = f.precision().unwrap_or(1).min(MAX_PRECISION);
+        // `precision` is at most `MAX_PRECISION`, so this cannot truncate
+        #[allow(clippy::cast_possible_truncation, reason = "precision is capped")]
+        let scale = 10_u128.pow(precision as u32);
+        let whole = u128::from(self.whole);
+        let scaled = (u128::from(self.part) * 100 * scale + whole / 2) / whole;
+
+        match precision {
+            0 => write!(f, "{scaled}%"),
+            _ => write!(f, "{}.{:0precision$}%", scaled / scale, scaled % scale),
+        }
+    }
+}
+
+impl Debug for Percent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// A type that formats a ratio of two counts, like `3/7`.
+///
+/// The alternate flag (`#`) appends the ratio as a [`Percent`], using the formatter's precision
+/// flag, like `3/7 (42.9%)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::Ratio;
+/// assert_eq!(format!("{}", Ratio::new(3, 7)), "3/7");
+/// assert_eq!(format!("{:#}", Ratio::new(3, 7)), "3/7 (42.9%)");
+/// assert_eq!(format!("{:#.0?}", Ratio::new(3, 7)), "3/7 (43%)");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the ratio as `numerator/denominator`
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ratio {
+    /// The numerator of the ratio.
+    pub numerator: u64,
+    /// The denominator of the ratio.
+    pub denominator: u64,
+}
+
+impl Ratio {
+    /// Creates a new [`Ratio`] of `numerator` to `denominator`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Ratio;
+    /// assert_eq!(format!("{:?}", Ratio::new(1, 2)), "1/2");
+    /// ```
+    #[must_use]
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
+        Self { numerator, denominator }
+    }
+
+    /// Returns the ratio as a [`Percent`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::Ratio;
+    /// assert_eq!(format!("{}", Ratio::new(1, 8).percent()), "12.5%");
+    /// ```
+    #[must_use]
+    pub const fn percent(self) -> Percent {
+        Percent::new(self.numerator, self.denominator)
+    }
+}
+
+impl Display for Ratio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)?;
+        if f.alternate() {
+            f.write_str(" (")?;
+            Display::fmt(&self.percent(), f)?;
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Ratio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
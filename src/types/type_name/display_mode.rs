@@ -1,6 +1,17 @@
 /// Sealed marker trait for type name display modes.
 #[sealed::sealed]
 pub trait DisplayMode {
+    /// The runtime equivalent of this display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::{DisplayMode, Full, Short, TypeNameMode};
+    /// assert_eq!(Full::MODE, TypeNameMode::Full);
+    /// assert_eq!(Short::MODE, TypeNameMode::Short);
+    /// ```
+    const MODE: TypeNameMode;
+
     /// Returns the type name for the given type according to this display mode.
     ///
     /// # Examples
@@ -22,6 +33,8 @@ pub struct Full;
 
 #[sealed::sealed]
 impl DisplayMode for Full {
+    const MODE: TypeNameMode = TypeNameMode::Full;
+
     fn type_name<T: ?Sized>() -> &'static str {
         core::any::type_name::<T>()
     }
@@ -33,8 +46,42 @@ pub struct Short;
 
 #[sealed::sealed]
 impl DisplayMode for Short {
+    const MODE: TypeNameMode = TypeNameMode::Short;
+
     fn type_name<T: ?Sized>() -> &'static str {
         let type_name = core::any::type_name::<T>();
         type_name.rsplit("::").next().unwrap_or(type_name)
     }
 }
+
+/// A runtime-selectable equivalent of the [`DisplayMode`] types.
+///
+/// Useful when the display mode is only known at runtime, see
+/// [`FormatAs::TypeName`](crate::wrap::FormatAs::TypeName).
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::TypeNameMode;
+/// assert_eq!(TypeNameMode::Full.type_name::<Vec<i32>>(), "alloc::vec::Vec<i32>");
+/// assert_eq!(TypeNameMode::Short.type_name::<Vec<i32>>(), "Vec<i32>");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TypeNameMode {
+    /// Shows the full type name, like [`Full`].
+    Full,
+    /// Shows only the short type name, like [`Short`].
+    #[default]
+    Short,
+}
+
+impl TypeNameMode {
+    /// Returns the type name for the given type according to this mode.
+    #[must_use]
+    pub fn type_name<T: ?Sized>(self) -> &'static str {
+        match self {
+            Self::Full => Full::type_name::<T>(),
+            Self::Short => Short::type_name::<T>(),
+        }
+    }
+}
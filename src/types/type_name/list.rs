@@ -3,13 +3,16 @@
 use core::fmt::{Debug, Formatter, Result};
 use core::marker::PhantomData;
 
-use crate::types::{DisplayMode, TypeName};
+use crate::types::{Count, DisplayMode, Grouped, TypeName};
 
 /// A type that formats as `[<Type>: N]` when used with [`Debug`].
 ///
 /// The type holds no data, and is only used for formatting. It can be used to summarize large
 /// collections or hide sensitive details, by only showing their element type and length.
 ///
+/// The count is a [`usize`], or a [`Grouped`] one from [`TypeNameList::grouped`], as set by the sealed
+/// [`Count`] trait.
+///
 /// # Examples
 ///
 /// ```rust
@@ -20,7 +23,7 @@ use crate::types::{DisplayMode, TypeName};
 /// let full = TypeNameList::<Vec<u8>, Full>::new(100);
 /// assert_eq!(format!("{:?}", full), "[<alloc::vec::Vec<u8>>: 100]");
 /// ```
-pub struct TypeNameList<T, M>(usize, PhantomData<(T, M)>);
+pub struct TypeNameList<T, M, C: Count = usize>(C, PhantomData<(T, M)>);
 
 impl<T, M> TypeNameList<T, M> {
     /// Creates a new [`TypeNameList`] with the given `count`.
@@ -66,9 +69,23 @@ impl<T, M> TypeNameList<T, M> {
     pub const fn len(&self) -> usize {
         self.0
     }
+
+    /// Groups the digits of the count, like `1_234_567`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::{TypeNameList, Short};
+    /// let grouped = TypeNameList::<u8, Short>::new(1_234_567).grouped();
+    /// assert_eq!(format!("{:?}", grouped), "[<u8>: 1_234_567]");
+    /// ```
+    #[must_use]
+    pub const fn grouped(self) -> TypeNameList<T, M, Grouped<usize>> {
+        TypeNameList(Grouped::new(self.0), PhantomData)
+    }
 }
 
-impl<T, M: DisplayMode> Debug for TypeNameList<T, M> {
+impl<T, M: DisplayMode, C: Count> Debug for TypeNameList<T, M, C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "[<{:?}>: {}]", TypeName::empty::<T, M>(), self.0)
     }
@@ -7,11 +7,15 @@ mod result;
 mod set;
 #[allow(clippy::module_inception)]
 mod type_name;
+mod values;
 
+pub use crate::wrap::FullyTypeNameResult;
 pub use crate::wrap::TypeName;
 pub use crate::wrap::TypeNameOption;
 pub use crate::wrap::TypeNameResult;
-pub use display_mode::{DisplayMode, Full, Short};
+pub use display_mode::{DisplayMode, Full, Short, TypeNameMode};
 pub use list::TypeNameList;
+pub use result::FullyTypeNameResultMarker;
 pub use set::{TypeNameMap, TypeNameSet};
 pub use type_name::TypeNameMarker;
+pub use values::TypeNameValues;
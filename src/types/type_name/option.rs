@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 
 use crate::types::{DisplayMode, Full, Short, TypeName};
-use crate::wrap::TypeNameOption;
+use crate::wrap::{OptionWith, TypeNameOption};
 
 /// An alias for an empty [`TypeNameOption`] that is used as a marker.
 pub type TypeNameOptionMarker<T, M> = TypeNameOption<T, PhantomData<fn() -> T>, M>;
@@ -15,7 +15,7 @@ impl<D: ?Sized> TypeNameOption<D, PhantomData<fn() -> D>, Short> {
     /// # use display_as_debug::types::TypeNameOption;
     /// assert_eq!(format!("{:?}", TypeNameOption::<Vec<i32>>::NONE), "None");
     /// ```
-    pub const NONE: TypeNameOptionMarker<D, Full> = TypeNameOption(None, TypeName::empty());
+    pub const NONE: TypeNameOptionMarker<D, Full> = OptionWith(None, TypeName::empty());
 
     /// A constant for [`Some`] containing a phantom value that uses the [`Full`] display mode.
     ///
@@ -26,7 +26,7 @@ impl<D: ?Sized> TypeNameOption<D, PhantomData<fn() -> D>, Short> {
     /// let full_some = TypeNameOption::<Vec<i32>>::SOME_FULL;
     /// assert_eq!(format!("{:?}", full_some), "Some(alloc::vec::Vec<i32>)");
     /// ```
-    pub const SOME_FULL: TypeNameOptionMarker<D, Full> = TypeNameOption(Some(PhantomData), TypeName::empty());
+    pub const SOME_FULL: TypeNameOptionMarker<D, Full> = OptionWith(Some(PhantomData), TypeName::empty());
 
     /// A constant for [`Some`] containing a phantom value that uses the [`Short`] display mode.
     ///
@@ -38,7 +38,7 @@ impl<D: ?Sized> TypeNameOption<D, PhantomData<fn() -> D>, Short> {
     /// assert_eq!(format!("{:?}", short_some), "Some(Vec<i32>)");
     /// ```
     #[allow(clippy::use_self, reason = "Symmetry with SOME_FULL")]
-    pub const SOME_SHORT: TypeNameOptionMarker<D, Short> = TypeNameOption(Some(PhantomData), TypeName::empty());
+    pub const SOME_SHORT: TypeNameOptionMarker<D, Short> = OptionWith(Some(PhantomData), TypeName::empty());
 }
 
 impl TypeNameOption {
@@ -60,8 +60,8 @@ impl TypeNameOption {
     #[must_use]
     pub const fn empty<D, M: DisplayMode>(option: &Option<D>) -> TypeNameOptionMarker<D, M> {
         match option {
-            Some(_) => TypeNameOption(Some(PhantomData), TypeName::empty()),
-            None => TypeNameOption(None, TypeName::empty()),
+            Some(_) => OptionWith(Some(PhantomData), TypeName::empty()),
+            None => OptionWith(None, TypeName::empty()),
         }
     }
 }
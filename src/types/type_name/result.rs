@@ -1,13 +1,14 @@
 use core::marker::PhantomData;
 
 use crate::types::{DisplayMode, Full, Short, TypeName};
-use crate::wrap::TypeNameResult;
+use crate::wrap::{AsIs, FullyTypeNameResult, ResultWith, TypeNameResult};
 
 /// An alias for an empty [`TypeNameResult`] that is used as an empty marker/[`Debug`]
 /// only type.
 ///
 /// Note, that because the [`Err`] variant of [`TypeNameResult`] is not empty
-/// (it displays the error value), a marker type can only represent the [`Ok`] variant.
+/// (it displays the error value), a marker type can only represent the [`Ok`] variant. See
+/// [`FullyTypeNameResultMarker`] for a marker that can also represent the [`Err`] variant.
 pub type TypeNameResultMarker<T, M> = TypeNameResult<PhantomData<fn() -> T>, (), T, M>;
 
 impl<T> TypeNameResult<T, ()> {
@@ -47,6 +48,87 @@ impl TypeNameResult<(), ()> {
     /// ```
     #[must_use]
     pub const fn ok_empty<T, M: DisplayMode>() -> TypeNameResultMarker<T, M> {
-        TypeNameResult(Ok(PhantomData), TypeName::empty())
+        ResultWith(Ok(PhantomData), TypeName::empty(), AsIs)
+    }
+}
+
+/// An alias for an empty [`FullyTypeNameResult`] that is used as an empty marker/[`Debug`]
+/// only type, for either the [`Ok`] or [`Err`] state.
+pub type FullyTypeNameResultMarker<T, E, M> =
+    FullyTypeNameResult<PhantomData<fn() -> T>, PhantomData<fn() -> E>, T, E, M>;
+
+impl<T, E> FullyTypeNameResult<T, E> {
+    /// An empty marker constant for [`Ok`] that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::FullyTypeNameResult;
+    /// let full_ok = FullyTypeNameResult::<Vec<i32>, core::fmt::Error>::OK_FULL;
+    /// assert_eq!(format!("{:?}", full_ok), "Ok(alloc::vec::Vec<i32>)");
+    /// ```
+    pub const OK_FULL: FullyTypeNameResultMarker<T, E, Full> = FullyTypeNameResult::ok_empty::<T, E, Full>();
+
+    /// An empty marker constant for [`Ok`] that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::FullyTypeNameResult;
+    /// let short_ok = FullyTypeNameResult::<Vec<i32>, core::fmt::Error>::OK_SHORT;
+    /// assert_eq!(format!("{:?}", short_ok), "Ok(Vec<i32>)");
+    /// ```
+    pub const OK_SHORT: FullyTypeNameResultMarker<T, E, Short> = FullyTypeNameResult::ok_empty::<T, E, Short>();
+
+    /// An empty marker constant for [`Err`] that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::FullyTypeNameResult;
+    /// let full_err = FullyTypeNameResult::<Vec<i32>, core::fmt::Error>::ERR_FULL;
+    /// assert_eq!(format!("{:?}", full_err), "Err(core::fmt::Error)");
+    /// ```
+    pub const ERR_FULL: FullyTypeNameResultMarker<T, E, Full> = FullyTypeNameResult::err_empty::<T, E, Full>();
+
+    /// An empty marker constant for [`Err`] that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::FullyTypeNameResult;
+    /// let short_err = FullyTypeNameResult::<Vec<i32>, core::fmt::Error>::ERR_SHORT;
+    /// assert_eq!(format!("{:?}", short_err), "Err(Error)");
+    /// ```
+    pub const ERR_SHORT: FullyTypeNameResultMarker<T, E, Short> = FullyTypeNameResult::err_empty::<T, E, Short>();
+}
+
+impl FullyTypeNameResult<(), ()> {
+    /// Create a new empty marker [`FullyTypeNameResult`] in the [`Ok`] state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::{Full, FullyTypeNameResult};
+    /// let marker = FullyTypeNameResult::ok_empty::<i32, core::fmt::Error, Full>();
+    /// assert_eq!(format!("{:?}", marker), "Ok(i32)");
+    /// ```
+    #[must_use]
+    pub const fn ok_empty<T, E, M: DisplayMode>() -> FullyTypeNameResultMarker<T, E, M> {
+        ResultWith(Ok(PhantomData), TypeName::empty(), TypeName::empty())
+    }
+
+    /// Create a new empty marker [`FullyTypeNameResult`] in the [`Err`] state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::{Full, FullyTypeNameResult};
+    /// let marker = FullyTypeNameResult::err_empty::<i32, core::fmt::Error, Full>();
+    /// assert_eq!(format!("{:?}", marker), "Err(core::fmt::Error)");
+    /// ```
+    #[must_use]
+    pub const fn err_empty<T, E, M: DisplayMode>() -> FullyTypeNameResultMarker<T, E, M> {
+        ResultWith(Err(PhantomData), TypeName::empty(), TypeName::empty())
     }
 }
@@ -3,7 +3,7 @@
 use core::fmt::{Debug, Formatter, Result};
 use core::marker::PhantomData;
 
-use crate::types::{DisplayMode, TypeName};
+use crate::types::{Count, DisplayMode, Grouped, TypeName};
 
 /// A type that formats as `{<Type>: N}` when used with [`Debug`].
 ///
@@ -12,6 +12,9 @@ use crate::types::{DisplayMode, TypeName};
 ///
 /// Also available as the [`TypeNameMap`] alias.
 ///
+/// The count is a [`usize`], or a [`Grouped`] one from [`TypeNameSet::grouped`], as set by the sealed
+/// [`Count`] trait.
+///
 /// # Example
 ///
 /// ```rust
@@ -22,7 +25,7 @@ use crate::types::{DisplayMode, TypeName};
 /// let full = TypeNameSet::<Vec<u8>, Full>::new(100);
 /// assert_eq!(format!("{:?}", full), "{<alloc::vec::Vec<u8>>: 100}");
 /// ```
-pub struct TypeNameSet<T, M>(usize, PhantomData<(T, M)>);
+pub struct TypeNameSet<T, M, C: Count = usize>(C, PhantomData<(T, M)>);
 
 /// Type alias for [`TypeNameSet`], useful when representing a map.
 ///
@@ -91,9 +94,23 @@ impl<T, M> TypeNameSet<T, M> {
     pub const fn len(&self) -> usize {
         self.0
     }
+
+    /// Groups the digits of the count, like `1_234_567`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::{TypeNameSet, Short};
+    /// let grouped = TypeNameSet::<u8, Short>::new(1_234_567).grouped();
+    /// assert_eq!(format!("{:?}", grouped), "{<u8>: 1_234_567}");
+    /// ```
+    #[must_use]
+    pub const fn grouped(self) -> TypeNameSet<T, M, Grouped<usize>> {
+        TypeNameSet(Grouped::new(self.0), PhantomData)
+    }
 }
 
-impl<T, M: DisplayMode> Debug for TypeNameSet<T, M> {
+impl<T, M: DisplayMode, C: Count> Debug for TypeNameSet<T, M, C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{{<{:?}>: {}}}", TypeName::empty::<T, M>(), self.0)
     }
@@ -53,3 +53,9 @@ impl TypeName {
         TypeName(PhantomData, PhantomData)
     }
 }
+
+impl<T: ?Sized, M: DisplayMode> Default for TypeNameMarker<T, M> {
+    fn default() -> Self {
+        TypeName::empty()
+    }
+}
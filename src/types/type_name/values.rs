@@ -0,0 +1,61 @@
+//! Implementation of [`TypeNameValues`]
+
+use core::fmt::{Debug, Formatter, Result};
+use core::marker::PhantomData;
+
+use crate::types::{DisplayMode, TypeName};
+
+/// A borrowed map that formats its values as their type name when used with [`Debug`].
+///
+/// Useful for maps whose values do not implement [`Debug`], or are too verbose to show. Declaring
+/// a field as a [`TypeNameValues`] allows `#[derive(Debug)]` to be used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::{TypeNameValues, Full, Short};
+/// # use std::collections::BTreeMap;
+/// #[derive(Debug)]
+/// struct Registry<'a> {
+///     handlers: TypeNameValues<'a, BTreeMap<&'static str, Vec<u8>>, Short>,
+/// }
+///
+/// let handlers = BTreeMap::from([("a", vec![1]), ("b", vec![2])]);
+/// let registry = Registry { handlers: TypeNameValues::new(&handlers) };
+/// assert_eq!(format!("{registry:?}"), r#"Registry { handlers: {"a": Vec<u8>, "b": Vec<u8>} }"#);
+///
+/// let full = TypeNameValues::<_, Full>::new(&handlers);
+/// assert_eq!(format!("{full:?}"), r#"{"a": alloc::vec::Vec<u8>, "b": alloc::vec::Vec<u8>}"#);
+/// ```
+pub struct TypeNameValues<'a, C: ?Sized, M>(pub &'a C, PhantomData<M>);
+
+impl<'a, C: ?Sized, M: DisplayMode> TypeNameValues<'a, C, M> {
+    /// Creates a new [`TypeNameValues`] wrapping `map`.
+    #[must_use]
+    pub const fn new(map: &'a C) -> Self {
+        Self(map, PhantomData)
+    }
+}
+
+impl<C: ?Sized, M> Clone for TypeNameValues<'_, C, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized, M> Copy for TypeNameValues<'_, C, M> {}
+
+impl<'a, C: ?Sized, M: DisplayMode> From<&'a C> for TypeNameValues<'a, C, M> {
+    fn from(map: &'a C) -> Self {
+        Self(map, PhantomData)
+    }
+}
+
+impl<'a, C: ?Sized, K: Debug, V: 'a, M: DisplayMode> Debug for TypeNameValues<'a, C, M>
+where
+    &'a C: IntoIterator<Item = (K, &'a V)>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self.0.into_iter().map(|(key, _)| (key, TypeName::empty::<V, M>()))).finish()
+    }
+}
@@ -0,0 +1,204 @@
+//! Implementation of [`UnixTime`]
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::types::TimeUnit;
+use crate::types::duration::fmt_subsec;
+use crate::types::pad::pad;
+use crate::write::FmtBuf;
+
+/// The number of days from `0000-03-01` to `1970-01-01`.
+const EPOCH_DAYS: i128 = 719_468;
+
+/// The number of days in a 400 year era of the Gregorian calendar.
+const ERA_DAYS: i128 = 146_097;
+
+/// The longest output: an 18 character year, 15 characters of date and time, a 10 character
+/// fraction, the `Z` and a 23 character alternate timestamp.
+const MAX_LEN: usize = 18 + 15 + 10 + 1 + 23;
+
+/// A type that formats a Unix timestamp as an RFC 3339 UTC date and time, like
+/// `2026-10-17T12:34:56Z`.
+///
+/// The timestamp is stored as the raw integer and the [`TimeUnit`] it counts, so any of the usual
+/// second, millisecond, microsecond or nanosecond timestamps can be formatted without conversion.
+/// The date is computed using the proleptic Gregorian calendar, without a date-time dependency.
+///
+/// Fractional seconds are shown without trailing zeros, or with the number of digits set by the
+/// formatter's precision flag (up to 9, truncated). Years outside `0000` to `9999` are shown with a
+/// sign, like `+10000`. The alternate flag (`#`) appends the raw timestamp. The formatter's width,
+/// fill and alignment are supported, left aligned by default. No allocation is performed.
+///
+/// With the `std` feature, a `SystemTime` can be converted into a
+/// [`UnixTime`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::types::UnixTime;
+/// assert_eq!(format!("{}", UnixTime::from_secs(1_792_240_496)), "2026-10-17T12:34:56Z");
+/// assert_eq!(format!("{:#}", UnixTime::from_secs(1_792_240_496)), "2026-10-17T12:34:56Z (1792240496)");
+///
+/// assert_eq!(format!("{:?}", UnixTime::from_millis(1_792_240_496_250)), "2026-10-17T12:34:56.25Z");
+/// assert_eq!(format!("{:.3?}", UnixTime::from_secs(-1)), "1969-12-31T23:59:59.000Z");
+/// assert_eq!(format!("[{:>22}]", UnixTime::from_secs(0)), "[  1970-01-01T00:00:00Z]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats the timestamp as RFC 3339 UTC
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UnixTime {
+    /// The raw timestamp, counted in `unit`s since `1970-01-01T00:00:00Z`.
+    pub timestamp: i64,
+    /// The unit of the timestamp.
+    pub unit: TimeUnit,
+}
+
+impl UnixTime {
+    /// Creates a new [`UnixTime`] from a timestamp counted in the given unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::{TimeUnit, UnixTime};
+    /// assert_eq!(format!("{}", UnixTime::new(20_743, TimeUnit::Days)), "2026-10-17T00:00:00Z");
+    /// ```
+    #[must_use]
+    pub const fn new(timestamp: i64, unit: TimeUnit) -> Self {
+        Self { timestamp, unit }
+    }
+
+    /// Creates a new [`UnixTime`] from a timestamp in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::UnixTime;
+    /// assert_eq!(format!("{}", UnixTime::from_secs(0)), "1970-01-01T00:00:00Z");
+    /// ```
+    #[must_use]
+    pub const fn from_secs(secs: i64) -> Self {
+        Self::new(secs, TimeUnit::Seconds)
+    }
+
+    /// Creates a new [`UnixTime`] from a timestamp in milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::UnixTime;
+    /// assert_eq!(format!("{}", UnixTime::from_millis(1_500)), "1970-01-01T00:00:01.5Z");
+    /// ```
+    #[must_use]
+    pub const fn from_millis(millis: i64) -> Self {
+        Self::new(millis, TimeUnit::Millis)
+    }
+
+    /// Creates a new [`UnixTime`] from a timestamp in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::UnixTime;
+    /// assert_eq!(format!("{}", UnixTime::from_micros(-1)), "1969-12-31T23:59:59.999999Z");
+    /// ```
+    #[must_use]
+    pub const fn from_micros(micros: i64) -> Self {
+        Self::new(micros, TimeUnit::Micros)
+    }
+
+    /// Creates a new [`UnixTime`] from a timestamp in nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::types::UnixTime;
+    /// assert_eq!(format!("{:#}", UnixTime::from_nanos(7)), "1970-01-01T00:00:00.000000007Z (7)");
+    /// ```
+    #[must_use]
+    pub const fn from_nanos(nanos: i64) -> Self {
+        Self::new(nanos, TimeUnit::Nanos)
+    }
+}
+
+/// Converts a [`SystemTime`] to nanoseconds, or to seconds if it is too far from the epoch for
+/// nanoseconds to fit, saturating at the limits of an [`i64`].
+#[cfg(feature = "std")]
+impl From<SystemTime> for UnixTime {
+    fn from(time: SystemTime) -> Self {
+        let (duration, sign) = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (duration, 1),
+            Err(error) => (error.duration(), -1),
+        };
+
+        i64::try_from(duration.as_nanos()).map_or_else(
+            |_| Self::from_secs(sign * i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)),
+            |nanos| Self::from_nanos(sign * nanos),
+        )
+    }
+}
+
+impl UnixTime {
+    /// Writes the timestamp as RFC 3339 UTC, without padding.
+    fn write_to(&self, w: &mut impl Write, precision: Option<usize>, alternate: bool) -> Result {
+        // a unit is at most a day in nanoseconds, so this cannot wrap
+        #[allow(clippy::cast_possible_wrap, reason = "unit is at most a day")]
+        let nanos = i128::from(self.timestamp) * self.unit.nanos() as i128;
+        let secs = nanos.div_euclid(1_000_000_000);
+        let (days, secs_of_day) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+        let (year, month, day) = civil_from_days(days);
+        let (hour, minute, second) = (secs_of_day / 3_600, secs_of_day % 3_600 / 60, secs_of_day % 60);
+
+        if (0..=9_999).contains(&year) {
+            write!(w, "{year:04}")?;
+        } else {
+            write!(w, "{year:+05}")?;
+        }
+        write!(w, "-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}")?;
+
+        // the remainder is less than a second, so this cannot truncate
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, reason = "remainder is in 0..1e9")]
+        fmt_subsec(w, nanos.rem_euclid(1_000_000_000) as u32, precision)?;
+        w.write_str("Z")?;
+
+        if alternate { write!(w, " ({})", self.timestamp) } else { Ok(()) }
+    }
+}
+
+impl Display for UnixTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut buf = FmtBuf::<MAX_LEN>::new();
+        self.write_to(&mut buf, f.precision(), f.alternate())?;
+
+        pad(f, buf.as_str())
+    }
+}
+
+impl Debug for UnixTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Converts a number of days since `1970-01-01` to a `(year, month, day)` civil date in the
+/// proleptic Gregorian calendar.
+///
+/// Uses Howard Hinnant's `civil_from_days` algorithm, which counts years from March so that the
+/// leap day falls at the end of the year.
+const fn civil_from_days(days: i128) -> (i128, i128, i128) {
+    let days = days + EPOCH_DAYS;
+    let era = days.div_euclid(ERA_DAYS);
+    let day_of_era = days.rem_euclid(ERA_DAYS);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / (ERA_DAYS - 1)) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
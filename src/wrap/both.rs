@@ -0,0 +1,49 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+use crate::wrap::{DebugAsDisplay, DisplayAsDebug};
+
+/// A type adaptor that shows both the wrapped value's [`Display`] and [`Debug`] output together.
+///
+/// Useful for diagnosing why a value's [`Display`] output looks wrong, by showing it side-by-side
+/// with its [`Debug`] output.
+///
+/// The alternate flag puts the [`Debug`] output on its own line, so `{:#}` formats as
+/// `display\ndebug`, which suits multi-line values. It is also forwarded to the wrapped value, so
+/// its alternate [`Display`] and pretty [`Debug`] output are used, and `{:#?}` pretty prints the
+/// struct.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::Both;
+/// # use display_as_debug::types::TestValue;
+/// let both = Both(TestValue::TEST);
+/// assert_eq!(format!("{both}"), r#"Display("test") (Debug("test"))"#);
+/// assert_eq!(format!("{:#}", Both("a\nb")), "a\nb\n\"a\\nb\"");
+/// assert_eq!(format!("{both:?}"), r#"Both { display: Display("test"), debug: Debug("test") }"#);
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Formats as `display (debug)`, or `display\ndebug` with the alternate flag
+/// - **[`Debug`]**: Formats as `Both { display: display, debug: debug }`
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct Both<T>(pub T);
+
+impl<T: Display + Debug> Display for Both<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.alternate() {
+            write!(f, "{:#}\n{:#}", self.0, DebugAsDisplay(&self.0))
+        } else {
+            write!(f, "{} ({})", self.0, DebugAsDisplay(&self.0))
+        }
+    }
+}
+
+impl<T: Display + Debug> Debug for Both<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("Both").field("display", &DisplayAsDebug(&self.0)).field("debug", &self.0).finish()
+    }
+}
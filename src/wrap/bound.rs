@@ -0,0 +1,254 @@
+//! [`Bound`] wrapper types for specialized [`Debug`] formatting.
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::Bound;
+
+use derive_more::{AsMut, AsRef, Deref, Into};
+
+use crate::types::{DisplayMode, Full, Short, TypeName};
+use crate::wrap::strategy::{DebugStrategy, Debugged};
+use crate::wrap::{AsOpaque, AsTypeName};
+
+const STR_INCLUDED: &str = "Included";
+const STR_EXCLUDED: &str = "Excluded";
+const STR_UNBOUNDED: &str = "Unbounded";
+
+/// A [`Bound<T>`] wrapper that formats the [`Included`](Bound::Included) and
+/// [`Excluded`](Bound::Excluded) values according to the strategy `S`.
+///
+/// See [`OptionWith`](crate::wrap::OptionWith) for the available strategies.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::ops::Bound;
+/// # use display_as_debug::wrap::{AsDisplay, BoundWith};
+/// assert_eq!(format!("{:?}", BoundWith(Bound::Included("a"), AsDisplay)), "Included(a)");
+/// assert_eq!(format!("{:?}", BoundWith(Bound::<&str>::Unbounded, AsDisplay)), "Unbounded");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Deref, AsRef, AsMut, Into)]
+pub struct BoundWith<T, S>(
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub Bound<T>,
+    /// The strategy used to format the bound value.
+    #[into(ignore)]
+    pub S,
+);
+
+/// Borrows the value of a [`Bound`], like [`Bound::as_ref`].
+const fn as_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value),
+        Bound::Excluded(value) => Bound::Excluded(value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+impl<T, S: DebugStrategy<T>> Debug for BoundWith<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Bound::Included(value) => f.debug_tuple(STR_INCLUDED).field(&Debugged(&self.1, value)).finish(),
+            Bound::Excluded(value) => f.debug_tuple(STR_EXCLUDED).field(&Debugged(&self.1, value)).finish(),
+            Bound::Unbounded => f.write_str(STR_UNBOUNDED),
+        }
+    }
+}
+
+impl<T, S: Default> From<Bound<T>> for BoundWith<T, S> {
+    fn from(bound: Bound<T>) -> Self {
+        Self(bound, S::default())
+    }
+}
+
+/// A [`Bound<T>`] wrapper that implements [`Debug`] with opaque bound values.
+///
+/// Displays as `Included(..)`, `Excluded(..)`, or `Unbounded`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::ops::Bound;
+/// # use display_as_debug::wrap::OpaqueBound;
+/// assert_eq!(format!("{:?}", OpaqueBound(Bound::Included(42))), "Included(..)");
+/// assert_eq!(format!("{:?}", OpaqueBound(Bound::<i32>::Unbounded)), "Unbounded");
+/// ```
+pub type OpaqueBound<T> = BoundWith<T, AsOpaque>;
+
+/// Create a new [`OpaqueBound`](type@OpaqueBound) wrapper, equivalent to `BoundWith(bound, AsOpaque)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn OpaqueBound<T>(bound: Bound<T>) -> OpaqueBound<T> {
+    BoundWith(bound, AsOpaque)
+}
+
+/// An alias for an empty [`OpaqueBound`](type@OpaqueBound) that is used as an empty
+/// marker/[`Debug`] only type.
+pub type OpaqueBoundMarker = OpaqueBound<()>;
+
+impl BoundWith<(), AsOpaque> {
+    /// An empty marker constant for [`Included`](Bound::Included) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueBound;
+    /// assert_eq!(format!("{:?}", OpaqueBound::INCLUDED), "Included(..)");
+    /// ```
+    pub const INCLUDED: Self = Self(Bound::Included(()), AsOpaque);
+
+    /// An empty marker constant for [`Excluded`](Bound::Excluded) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueBound;
+    /// assert_eq!(format!("{:?}", OpaqueBound::EXCLUDED), "Excluded(..)");
+    /// ```
+    pub const EXCLUDED: Self = Self(Bound::Excluded(()), AsOpaque);
+
+    /// An empty marker constant for [`Unbounded`](Bound::Unbounded) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueBound;
+    /// assert_eq!(format!("{:?}", OpaqueBound::UNBOUNDED), "Unbounded");
+    /// ```
+    pub const UNBOUNDED: Self = Self(Bound::Unbounded, AsOpaque);
+}
+
+impl<T> BoundWith<T, AsOpaque> {
+    /// Create a new [`OpaqueBound`](type@OpaqueBound) wrapper that borrows the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::Bound;
+    /// # use display_as_debug::wrap::OpaqueBound;
+    /// let bound = Bound::Excluded("secret");
+    /// assert_eq!(format!("{:?}", OpaqueBound::borrow(&bound)), "Excluded(..)");
+    /// ```
+    #[must_use]
+    pub const fn borrow(bound: &Bound<T>) -> OpaqueBound<&T> {
+        BoundWith(as_ref(bound), AsOpaque)
+    }
+}
+
+/// A [`Bound<T>`] wrapper that implements [`Debug`], displaying type names instead of values.
+///
+/// Displays as `Included(typename)`, `Excluded(typename)`, or `Unbounded`.
+///
+/// The type parameters match those of [`TypeNameOption`](crate::wrap::TypeNameOption).
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::ops::Bound;
+/// # use display_as_debug::wrap::{Full, Short, TypeNameBound};
+/// let short = TypeNameBound::new::<Short>(Bound::Included(vec![1]));
+/// assert_eq!(format!("{:?}", short), "Included(Vec<i32>)");
+///
+/// let full = TypeNameBound::new::<Full>(Bound::Excluded(vec![1]));
+/// assert_eq!(format!("{:?}", full), "Excluded(alloc::vec::Vec<i32>)");
+/// ```
+pub type TypeNameBound<D = (), T = PhantomData<fn() -> D>, M = Short> = BoundWith<T, AsTypeName<D, M>>;
+
+/// An alias for an empty [`TypeNameBound`] that is used as a marker.
+pub type TypeNameBoundMarker<D, M> = TypeNameBound<D, PhantomData<fn() -> D>, M>;
+
+#[allow(clippy::mismatching_type_param_order, reason = "T is used for both Display and Value")]
+impl<T> BoundWith<T, AsTypeName<T>> {
+    /// Create a new [`TypeNameBound`] wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::Bound;
+    /// # use display_as_debug::wrap::{Short, TypeNameBound};
+    /// let bound = TypeNameBound::new::<Short>(Bound::Included(1_u8));
+    /// assert_eq!(format!("{:?}", bound), "Included(u8)");
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(bound: Bound<T>) -> TypeNameBound<T, T, M> {
+        BoundWith(bound, TypeName::empty())
+    }
+
+    /// Create a new [`TypeNameBound`] wrapper that borrows the value but displays the inner type
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::Bound;
+    /// # use display_as_debug::wrap::{Short, TypeNameBound};
+    /// let bound = Bound::Excluded(vec![1]);
+    /// assert_eq!(format!("{:?}", TypeNameBound::borrow::<Short>(&bound)), "Excluded(Vec<i32>)");
+    /// ```
+    #[must_use]
+    pub const fn borrow<M: DisplayMode>(bound: &Bound<T>) -> TypeNameBound<T, &T, M> {
+        BoundWith(as_ref(bound), TypeName::empty())
+    }
+}
+
+impl<D: ?Sized> BoundWith<PhantomData<fn() -> D>, AsTypeName<D>> {
+    /// A constant for [`Unbounded`](Bound::Unbounded).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameBound;
+    /// assert_eq!(format!("{:?}", TypeNameBound::<Vec<i32>>::UNBOUNDED), "Unbounded");
+    /// ```
+    pub const UNBOUNDED: TypeNameBoundMarker<D, Full> = BoundWith(Bound::Unbounded, TypeName::empty());
+
+    /// A constant for [`Included`](Bound::Included) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameBound;
+    /// let included = TypeNameBound::<Vec<i32>>::INCLUDED_FULL;
+    /// assert_eq!(format!("{:?}", included), "Included(alloc::vec::Vec<i32>)");
+    /// ```
+    pub const INCLUDED_FULL: TypeNameBoundMarker<D, Full> = BoundWith(Bound::Included(PhantomData), TypeName::empty());
+
+    /// A constant for [`Included`](Bound::Included) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameBound;
+    /// let included = TypeNameBound::<Vec<i32>>::INCLUDED_SHORT;
+    /// assert_eq!(format!("{:?}", included), "Included(Vec<i32>)");
+    /// ```
+    #[allow(clippy::use_self, reason = "Symmetry with INCLUDED_FULL")]
+    pub const INCLUDED_SHORT: TypeNameBoundMarker<D, Short> =
+        BoundWith(Bound::Included(PhantomData), TypeName::empty());
+
+    /// A constant for [`Excluded`](Bound::Excluded) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameBound;
+    /// let excluded = TypeNameBound::<Vec<i32>>::EXCLUDED_FULL;
+    /// assert_eq!(format!("{:?}", excluded), "Excluded(alloc::vec::Vec<i32>)");
+    /// ```
+    pub const EXCLUDED_FULL: TypeNameBoundMarker<D, Full> = BoundWith(Bound::Excluded(PhantomData), TypeName::empty());
+
+    /// A constant for [`Excluded`](Bound::Excluded) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameBound;
+    /// let excluded = TypeNameBound::<Vec<i32>>::EXCLUDED_SHORT;
+    /// assert_eq!(format!("{:?}", excluded), "Excluded(Vec<i32>)");
+    /// ```
+    #[allow(clippy::use_self, reason = "Symmetry with EXCLUDED_FULL")]
+    pub const EXCLUDED_SHORT: TypeNameBoundMarker<D, Short> =
+        BoundWith(Bound::Excluded(PhantomData), TypeName::empty());
+}
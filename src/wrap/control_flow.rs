@@ -0,0 +1,250 @@
+//! [`ControlFlow`] wrapper types for specialized [`Debug`] formatting.
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+use derive_more::{AsMut, AsRef, Deref, Into};
+
+use crate::types::{DisplayMode, Full, Short, TypeName};
+use crate::wrap::strategy::{DebugStrategy, Debugged};
+use crate::wrap::{AsOpaque, AsTypeName};
+
+const STR_BREAK: &str = "Break";
+const STR_CONTINUE: &str = "Continue";
+
+/// A [`ControlFlow<B, C>`] wrapper that formats the [`Break`](ControlFlow::Break) value according to
+/// the strategy `SB`, and the [`Continue`](ControlFlow::Continue) value according to the strategy
+/// `SC`.
+///
+/// See [`ResultWith`](crate::wrap::ResultWith) for the available strategies.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::ops::ControlFlow;
+/// # use display_as_debug::wrap::{AsDisplay, AsOpaque, ControlFlowWith};
+/// let flow = ControlFlowWith(ControlFlow::<&str, i32>::Break("stop"), AsDisplay, AsOpaque);
+/// assert_eq!(format!("{:?}", flow), "Break(stop)");
+///
+/// let flow = ControlFlowWith(ControlFlow::<&str, i32>::Continue(1), AsDisplay, AsOpaque);
+/// assert_eq!(format!("{:?}", flow), "Continue(..)");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Deref, AsRef, AsMut, Into)]
+pub struct ControlFlowWith<B, C, SB, SC>(
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub ControlFlow<B, C>,
+    /// The strategy used to format the [`Break`](ControlFlow::Break) value.
+    #[into(ignore)]
+    pub SB,
+    /// The strategy used to format the [`Continue`](ControlFlow::Continue) value.
+    #[into(ignore)]
+    pub SC,
+);
+
+/// Borrows the values of a [`ControlFlow`], like [`Result::as_ref`].
+const fn as_ref<B, C>(flow: &ControlFlow<B, C>) -> ControlFlow<&B, &C> {
+    match flow {
+        ControlFlow::Break(value) => ControlFlow::Break(value),
+        ControlFlow::Continue(value) => ControlFlow::Continue(value),
+    }
+}
+
+impl<B, C, SB: DebugStrategy<B>, SC: DebugStrategy<C>> Debug for ControlFlowWith<B, C, SB, SC> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            ControlFlow::Break(value) => f.debug_tuple(STR_BREAK).field(&Debugged(&self.1, value)).finish(),
+            ControlFlow::Continue(value) => f.debug_tuple(STR_CONTINUE).field(&Debugged(&self.2, value)).finish(),
+        }
+    }
+}
+
+impl<B, C, SB: Default, SC: Default> From<ControlFlow<B, C>> for ControlFlowWith<B, C, SB, SC> {
+    fn from(flow: ControlFlow<B, C>) -> Self {
+        Self(flow, SB::default(), SC::default())
+    }
+}
+
+/// A [`ControlFlow<B, C>`] wrapper that implements [`Debug`] with opaque values.
+///
+/// Displays as `Break(..)` or `Continue(..)`, without requiring either value to implement [`Debug`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::ops::ControlFlow;
+/// # use display_as_debug::wrap::OpaqueControlFlow;
+/// assert_eq!(format!("{:?}", OpaqueControlFlow(ControlFlow::<_, ()>::Break(42))), "Break(..)");
+/// assert_eq!(format!("{:?}", OpaqueControlFlow(ControlFlow::<i32>::Continue(()))), "Continue(..)");
+/// ```
+pub type OpaqueControlFlow<B, C = ()> = ControlFlowWith<B, C, AsOpaque, AsOpaque>;
+
+/// Create a new [`OpaqueControlFlow`](type@OpaqueControlFlow) wrapper, equivalent to
+/// `ControlFlowWith(flow, AsOpaque, AsOpaque)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn OpaqueControlFlow<B, C>(flow: ControlFlow<B, C>) -> OpaqueControlFlow<B, C> {
+    ControlFlowWith(flow, AsOpaque, AsOpaque)
+}
+
+/// An alias for an empty [`OpaqueControlFlow`](type@OpaqueControlFlow) that is used as an empty
+/// marker/[`Debug`] only type.
+pub type OpaqueControlFlowMarker = OpaqueControlFlow<(), ()>;
+
+impl ControlFlowWith<(), (), AsOpaque, AsOpaque> {
+    /// An empty marker constant for [`Break`](ControlFlow::Break) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueControlFlow;
+    /// assert_eq!(format!("{:?}", OpaqueControlFlow::BREAK), "Break(..)");
+    /// ```
+    pub const BREAK: Self = Self(ControlFlow::Break(()), AsOpaque, AsOpaque);
+
+    /// An empty marker constant for [`Continue`](ControlFlow::Continue) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueControlFlow;
+    /// assert_eq!(format!("{:?}", OpaqueControlFlow::CONTINUE), "Continue(..)");
+    /// ```
+    pub const CONTINUE: Self = Self(ControlFlow::Continue(()), AsOpaque, AsOpaque);
+}
+
+impl<B, C> ControlFlowWith<B, C, AsOpaque, AsOpaque> {
+    /// Create a new [`OpaqueControlFlow`](type@OpaqueControlFlow) wrapper that borrows the wrapped
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::ControlFlow;
+    /// # use display_as_debug::wrap::OpaqueControlFlow;
+    /// let flow = ControlFlow::<_, ()>::Break("secret");
+    /// assert_eq!(format!("{:?}", OpaqueControlFlow::borrow(&flow)), "Break(..)");
+    /// ```
+    #[must_use]
+    pub const fn borrow(flow: &ControlFlow<B, C>) -> OpaqueControlFlow<&B, &C> {
+        ControlFlowWith(as_ref(flow), AsOpaque, AsOpaque)
+    }
+}
+
+/// A [`ControlFlow<B, C>`] wrapper that implements [`Debug`], displaying type names instead of
+/// values.
+///
+/// Displays as `Break(typename)` or `Continue(typename)`.
+///
+/// # Type Parameters
+///
+/// - `B`: The type of the value in the [`Break`](ControlFlow::Break) variant.
+/// - `C`: The type of the value in the [`Continue`](ControlFlow::Continue) variant.
+/// - `DB`: The type that is displayed in the [`Break`](ControlFlow::Break) variant.
+/// - `DC`: The type that is displayed in the [`Continue`](ControlFlow::Continue) variant.
+///   - Both are used internally to display the type names as `B` and `C` when borrowing a
+///     [`ControlFlow`].
+/// - `M`: The display mode for the type names.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::ops::ControlFlow;
+/// # use display_as_debug::wrap::{Full, Short, TypeNameControlFlow};
+/// let flow = ControlFlow::<_, ()>::Break(vec![1_u8]);
+/// assert_eq!(format!("{:?}", TypeNameControlFlow::new::<Short>(flow)), "Break(Vec<u8>)");
+///
+/// let flow = ControlFlow::<Vec<u8>, _>::Continue(1);
+/// assert_eq!(format!("{:?}", TypeNameControlFlow::new::<Full>(flow)), "Continue(i32)");
+/// ```
+pub type TypeNameControlFlow<B, C = (), DB = B, DC = C, M = Short> =
+    ControlFlowWith<B, C, AsTypeName<DB, M>, AsTypeName<DC, M>>;
+
+/// An alias for an empty [`TypeNameControlFlow`] that is used as a marker.
+pub type TypeNameControlFlowMarker<B, C, M> =
+    TypeNameControlFlow<PhantomData<fn() -> B>, PhantomData<fn() -> C>, B, C, M>;
+
+impl<B, C> ControlFlowWith<B, C, AsTypeName<B>, AsTypeName<C>> {
+    /// Create a new [`TypeNameControlFlow`] wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::ControlFlow;
+    /// # use display_as_debug::wrap::{Short, TypeNameControlFlow};
+    /// let flow = ControlFlow::<_, ()>::Break(1_u8);
+    /// assert_eq!(format!("{:?}", TypeNameControlFlow::new::<Short>(flow)), "Break(u8)");
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(flow: ControlFlow<B, C>) -> TypeNameControlFlow<B, C, B, C, M> {
+        ControlFlowWith(flow, TypeName::empty(), TypeName::empty())
+    }
+
+    /// Create a new [`TypeNameControlFlow`] wrapper that borrows the value but displays the inner
+    /// type names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::ControlFlow;
+    /// # use display_as_debug::wrap::{Short, TypeNameControlFlow};
+    /// let flow = ControlFlow::<_, ()>::Break(vec![1_u8]);
+    /// assert_eq!(format!("{:?}", TypeNameControlFlow::borrow::<Short>(&flow)), "Break(Vec<u8>)");
+    /// ```
+    #[must_use]
+    pub const fn borrow<M: DisplayMode>(flow: &ControlFlow<B, C>) -> TypeNameControlFlow<&B, &C, B, C, M> {
+        ControlFlowWith(as_ref(flow), TypeName::empty(), TypeName::empty())
+    }
+}
+
+impl<B, C> TypeNameControlFlow<B, C> {
+    /// A constant for [`Break`](ControlFlow::Break) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameControlFlow;
+    /// let flow = TypeNameControlFlow::<Vec<u8>>::BREAK_FULL;
+    /// assert_eq!(format!("{:?}", flow), "Break(alloc::vec::Vec<u8>)");
+    /// ```
+    pub const BREAK_FULL: TypeNameControlFlowMarker<B, C, Full> =
+        ControlFlowWith(ControlFlow::Break(PhantomData), TypeName::empty(), TypeName::empty());
+
+    /// A constant for [`Break`](ControlFlow::Break) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameControlFlow;
+    /// let flow = TypeNameControlFlow::<Vec<u8>>::BREAK_SHORT;
+    /// assert_eq!(format!("{:?}", flow), "Break(Vec<u8>)");
+    /// ```
+    pub const BREAK_SHORT: TypeNameControlFlowMarker<B, C, Short> =
+        ControlFlowWith(ControlFlow::Break(PhantomData), TypeName::empty(), TypeName::empty());
+
+    /// A constant for [`Continue`](ControlFlow::Continue) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameControlFlow;
+    /// let flow = TypeNameControlFlow::<Vec<u8>>::CONTINUE_FULL;
+    /// assert_eq!(format!("{:?}", flow), "Continue(())");
+    /// ```
+    pub const CONTINUE_FULL: TypeNameControlFlowMarker<B, C, Full> =
+        ControlFlowWith(ControlFlow::Continue(PhantomData), TypeName::empty(), TypeName::empty());
+
+    /// A constant for [`Continue`](ControlFlow::Continue) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameControlFlow;
+    /// let flow = TypeNameControlFlow::<Vec<u8>, String>::CONTINUE_SHORT;
+    /// assert_eq!(format!("{:?}", flow), "Continue(String)");
+    /// ```
+    pub const CONTINUE_SHORT: TypeNameControlFlowMarker<B, C, Short> =
+        ControlFlowWith(ControlFlow::Continue(PhantomData), TypeName::empty(), TypeName::empty());
+}
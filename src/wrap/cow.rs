@@ -0,0 +1,247 @@
+//! [`Cow`] wrapper types for specialized [`Debug`] formatting.
+
+use alloc::borrow::{Cow, ToOwned};
+use core::borrow::Borrow;
+use core::fmt::{Debug, Formatter};
+
+use derive_more::{AsMut, AsRef, Deref, Into};
+
+use crate::types::{DisplayMode, Full, Short, TypeName};
+use crate::wrap::strategy::{DebugStrategy, Debugged};
+use crate::wrap::{AsOpaque, AsTypeName};
+
+const STR_BORROWED: &str = "Borrowed";
+const STR_OWNED: &str = "Owned";
+
+/// A [`Cow<B>`] wrapper that formats the borrowed or owned value according to the strategy `S`.
+///
+/// The strategy is always applied to the borrowed form of the value (`&B`), so both variants
+/// format the same way, only the variant name differs.
+///
+/// See [`OptionWith`](crate::wrap::OptionWith) for the available strategies.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use display_as_debug::wrap::{AsDisplay, CowWith};
+/// assert_eq!(format!("{:?}", CowWith(Cow::Borrowed("text"), AsDisplay)), "Borrowed(text)");
+/// assert_eq!(format!("{:?}", CowWith(Cow::<str>::Owned("text".into()), AsDisplay)), "Owned(text)");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut, Into)]
+pub struct CowWith<'a, B: ?Sized + ToOwned, S>(
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub Cow<'a, B>,
+    /// The strategy used to format the value.
+    #[into(ignore)]
+    pub S,
+);
+
+/// Borrows the value of a [`Cow`], keeping its variant.
+#[allow(clippy::ptr_arg, reason = "The variant of the Cow is kept")]
+fn as_ref<'b, B: ?Sized + ToOwned>(cow: &'b Cow<'_, B>) -> Cow<'b, &'b B> {
+    match cow {
+        Cow::Borrowed(borrowed) => Cow::Borrowed(borrowed),
+        Cow::Owned(owned) => Cow::Owned(owned.borrow()),
+    }
+}
+
+impl<B: ?Sized + ToOwned, S: Clone> Clone for CowWith<'_, B, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<B: ?Sized + ToOwned, S: DebugStrategy<B>> Debug for CowWith<'_, B, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let name = match &self.0 {
+            Cow::Borrowed(_) => STR_BORROWED,
+            Cow::Owned(_) => STR_OWNED,
+        };
+        f.debug_tuple(name).field(&Debugged(&self.1, &*self.0)).finish()
+    }
+}
+
+impl<'a, B: ?Sized + ToOwned, S: Default> From<Cow<'a, B>> for CowWith<'a, B, S> {
+    fn from(cow: Cow<'a, B>) -> Self {
+        Self(cow, S::default())
+    }
+}
+
+/// A [`Cow<B>`] wrapper that implements [`Debug`] with opaque values.
+///
+/// Displays as `Borrowed(..)` or `Owned(..)`, without requiring the value to implement [`Debug`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use display_as_debug::wrap::OpaqueCow;
+/// assert_eq!(format!("{:?}", OpaqueCow(Cow::Borrowed("secret"))), "Borrowed(..)");
+/// assert_eq!(format!("{:?}", OpaqueCow(Cow::<str>::Owned("secret".into()))), "Owned(..)");
+/// ```
+pub type OpaqueCow<'a, B> = CowWith<'a, B, AsOpaque>;
+
+/// Create a new [`OpaqueCow`](type@OpaqueCow) wrapper, equivalent to `CowWith(cow, AsOpaque)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn OpaqueCow<B: ?Sized + ToOwned>(cow: Cow<'_, B>) -> OpaqueCow<'_, B> {
+    CowWith(cow, AsOpaque)
+}
+
+/// An alias for an empty [`OpaqueCow`](type@OpaqueCow) that is used as an empty marker/[`Debug`]
+/// only type.
+pub type OpaqueCowMarker = OpaqueCow<'static, ()>;
+
+impl CowWith<'static, (), AsOpaque> {
+    /// An empty marker constant for [`Borrowed`](Cow::Borrowed) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueCow;
+    /// assert_eq!(format!("{:?}", OpaqueCow::BORROWED), "Borrowed(..)");
+    /// ```
+    pub const BORROWED: Self = Self(Cow::Borrowed(&()), AsOpaque);
+
+    /// An empty marker constant for [`Owned`](Cow::Owned) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaqueCow;
+    /// assert_eq!(format!("{:?}", OpaqueCow::OWNED), "Owned(..)");
+    /// ```
+    pub const OWNED: Self = Self(Cow::Owned(()), AsOpaque);
+}
+
+impl<B: ?Sized + ToOwned> CowWith<'_, B, AsOpaque> {
+    /// Create a new [`OpaqueCow`](type@OpaqueCow) wrapper that borrows the wrapped value, keeping its
+    /// variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use display_as_debug::wrap::OpaqueCow;
+    /// let cow = Cow::<str>::Owned("secret".into());
+    /// assert_eq!(format!("{:?}", OpaqueCow::borrow(&cow)), "Owned(..)");
+    /// ```
+    #[must_use]
+    #[allow(clippy::ptr_arg, reason = "The variant of the Cow is kept")]
+    pub fn borrow<'b>(cow: &'b Cow<'_, B>) -> OpaqueCow<'b, &'b B> {
+        CowWith(as_ref(cow), AsOpaque)
+    }
+}
+
+/// A [`Cow<B>`] wrapper that implements [`Debug`], displaying type names instead of values.
+///
+/// Displays as `Borrowed(typename)` or `Owned(typename)`, where `typename` is the name of the
+/// borrowed type `B` in both cases.
+///
+/// # Type Parameters
+///
+/// - `D`: The type that is displayed.
+/// - `B`: The borrowed type of the wrapped [`Cow`].
+///   - These differ only for the empty marker constants, where `B` is `()`.
+/// - `M`: The display mode for the type name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use display_as_debug::wrap::{Full, Short, TypeNameCow};
+/// let short = TypeNameCow::new::<Short>(Cow::Borrowed(&[1_u8][..]));
+/// assert_eq!(format!("{:?}", short), "Borrowed([u8])");
+///
+/// let full = TypeNameCow::new::<Full>(Cow::<str>::Owned("text".into()));
+/// assert_eq!(format!("{:?}", full), "Owned(str)");
+/// ```
+pub type TypeNameCow<'a, D = (), B = (), M = Short> = CowWith<'a, B, AsTypeName<D, M>>;
+
+/// An alias for an empty [`TypeNameCow`] that is used as a marker.
+pub type TypeNameCowMarker<D, M> = TypeNameCow<'static, D, (), M>;
+
+impl<'a, B: ?Sized + ToOwned> CowWith<'a, B, AsTypeName<B>> {
+    /// Create a new [`TypeNameCow`] wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use display_as_debug::wrap::{Short, TypeNameCow};
+    /// let cow = TypeNameCow::new::<Short>(Cow::<[u8]>::Owned(vec![1]));
+    /// assert_eq!(format!("{:?}", cow), "Owned([u8])");
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(cow: Cow<'a, B>) -> TypeNameCow<'a, B, B, M> {
+        CowWith(cow, TypeName::empty())
+    }
+
+    /// Create a new [`TypeNameCow`] wrapper that borrows the value, keeping its variant, but
+    /// displays the inner type name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use display_as_debug::wrap::{Short, TypeNameCow};
+    /// let cow = Cow::<[u8]>::Owned(vec![1]);
+    /// assert_eq!(format!("{:?}", TypeNameCow::borrow::<Short>(&cow)), "Owned([u8])");
+    /// ```
+    #[must_use]
+    #[allow(clippy::ptr_arg, reason = "The variant of the Cow is kept")]
+    pub fn borrow<'b, M: DisplayMode>(cow: &'b Cow<'_, B>) -> TypeNameCow<'b, B, &'b B, M> {
+        CowWith(as_ref(cow), TypeName::empty())
+    }
+}
+
+impl<D: ?Sized> CowWith<'static, (), AsTypeName<D>> {
+    /// A constant for [`Borrowed`](Cow::Borrowed) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameCow;
+    /// let borrowed = TypeNameCow::<String>::BORROWED_FULL;
+    /// assert_eq!(format!("{:?}", borrowed), "Borrowed(alloc::string::String)");
+    /// ```
+    pub const BORROWED_FULL: TypeNameCowMarker<D, Full> = CowWith(Cow::Borrowed(&()), TypeName::empty());
+
+    /// A constant for [`Borrowed`](Cow::Borrowed) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameCow;
+    /// let borrowed = TypeNameCow::<String>::BORROWED_SHORT;
+    /// assert_eq!(format!("{:?}", borrowed), "Borrowed(String)");
+    /// ```
+    #[allow(clippy::use_self, reason = "Symmetry with BORROWED_FULL")]
+    pub const BORROWED_SHORT: TypeNameCowMarker<D, Short> = CowWith(Cow::Borrowed(&()), TypeName::empty());
+
+    /// A constant for [`Owned`](Cow::Owned) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameCow;
+    /// let owned = TypeNameCow::<String>::OWNED_FULL;
+    /// assert_eq!(format!("{:?}", owned), "Owned(alloc::string::String)");
+    /// ```
+    pub const OWNED_FULL: TypeNameCowMarker<D, Full> = CowWith(Cow::Owned(()), TypeName::empty());
+
+    /// A constant for [`Owned`](Cow::Owned) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNameCow;
+    /// let owned = TypeNameCow::<String>::OWNED_SHORT;
+    /// assert_eq!(format!("{:?}", owned), "Owned(String)");
+    /// ```
+    #[allow(clippy::use_self, reason = "Symmetry with OWNED_FULL")]
+    pub const OWNED_SHORT: TypeNameCowMarker<D, Short> = CowWith(Cow::Owned(()), TypeName::empty());
+}
@@ -0,0 +1,79 @@
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+use super::writer::{EscapeWriter, write_display};
+
+/// A type adaptor that escapes the wrapped value's [`Display`] output.
+///
+/// Control characters, quotes, and backslashes are escaped as by [`char::escape_debug`], so
+/// untrusted text containing newlines or ANSI escape sequences cannot forge additional lines of
+/// output. The output is streamed through the escaper, no allocation is performed.
+///
+/// See [`Quoted`] for a variant that also wraps the output in quotes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::Escaped;
+/// assert_eq!(format!("{}", Escaped("line\nforged")), r"line\nforged");
+/// assert_eq!(format!("{:?}", Escaped("\x1b[31mred")), r"\u{1b}[31mred");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Uses the wrapped value's [`Display`] implementation, escaped
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct Escaped<T>(pub T);
+
+impl<T: Display> Display for Escaped<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let alternate = f.alternate();
+        write_display(&mut EscapeWriter::new(f, false), &self.0, alternate)
+    }
+}
+
+impl<T: Display> Debug for Escaped<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// A type adaptor that escapes the wrapped value's [`Display`] output and wraps it in quotes.
+///
+/// The output matches the [`Debug`] output of a [`str`] containing the same text, making it
+/// useful for emitting [`Debug`]-style quoted strings via
+/// [`DebugStructExt::field_display`](crate::fmt::DebugStructExt::field_display) and similar
+/// methods. Like [`Escaped`], no allocation is performed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::Quoted;
+/// # use display_as_debug::types::TestValue;
+/// assert_eq!(format!("{}", Quoted("it's\n")), r#""it's\n""#);
+/// assert_eq!(format!("{:?}", Quoted(TestValue::TEST)), r#""Display(\"test\")""#);
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Uses the wrapped value's [`Display`] implementation, escaped and quoted
+/// - **[`Debug`]**: Same as [`Display`]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct Quoted<T>(pub T);
+
+impl<T: Display> Display for Quoted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let alternate = f.alternate();
+        f.write_char('"')?;
+        write_display(&mut EscapeWriter::new(f, true), &self.0, alternate)?;
+        f.write_char('"')
+    }
+}
+
+impl<T: Display> Debug for Quoted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,84 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use crate::types::{OPAQUE, TypeNameMode};
+use crate::wrap::DisplayAsDebug;
+
+/// A runtime-selectable formatting strategy, used by [`Formatted`].
+///
+/// Each variant corresponds to one of the statically selected wrapper types, allowing the choice
+/// between them to be made at runtime (from configuration, log level, tenant, etc.).
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::{FormatAs, Formatted, TypeNameMode};
+/// # use display_as_debug::types::TestValue;
+/// let value = TestValue::TEST;
+/// assert_eq!(format!("{:?}", Formatted(&value, FormatAs::Debug)), r#"Debug("test")"#);
+/// assert_eq!(format!("{:?}", Formatted(&value, FormatAs::Display)), r#"Display("test")"#);
+/// assert_eq!(format!("{:?}", Formatted(&value, FormatAs::Opaque)), "..");
+///
+/// let type_name = FormatAs::TypeName(TypeNameMode::Short);
+/// assert_eq!(format!("{:?}", Formatted(&value, type_name)), "TestValue<&str>");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FormatAs {
+    /// Uses the value's [`Debug`] implementation.
+    #[default]
+    Debug,
+    /// Uses the value's [`Display`] implementation, like [`DisplayAsDebug`].
+    Display,
+    /// Obscures the value, like [`Opaque`](crate::wrap::Opaque).
+    Opaque,
+    /// Shows the value's type name, like [`TypeName`](crate::wrap::TypeName).
+    TypeName(TypeNameMode),
+}
+
+/// A wrapper that formats a borrowed value according to a runtime-selected [`FormatAs`] strategy.
+///
+/// Because the strategy is selected at runtime, the wrapped value must implement both [`Debug`]
+/// and [`Display`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::{FormatAs, Formatted, TypeNameMode};
+/// let redact = true;
+/// let strategy = if redact { FormatAs::Opaque } else { FormatAs::Debug };
+/// assert_eq!(format!("{:?}", Formatted(&"secret", strategy)), "..");
+///
+/// let full = FormatAs::TypeName(TypeNameMode::Full);
+/// assert_eq!(format!("{}", Formatted(&"secret", full)), "&str");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Formats the value according to the [`FormatAs`] strategy
+/// - **[`Display`]**: Same as [`Debug`]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Formatted<'a, T: ?Sized>(pub &'a T, pub FormatAs);
+
+impl<T: ?Sized> Clone for Formatted<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Formatted<'_, T> {}
+
+impl<T: Debug + Display + ?Sized> Debug for Formatted<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.1 {
+            FormatAs::Debug => Debug::fmt(self.0, f),
+            FormatAs::Display => Debug::fmt(&DisplayAsDebug(self.0), f),
+            FormatAs::Opaque => Debug::fmt(&OPAQUE, f),
+            FormatAs::TypeName(mode) => f.write_str(mode.type_name::<T>()),
+        }
+    }
+}
+
+impl<T: Debug + Display + ?Sized> Display for Formatted<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self, f)
+    }
+}
@@ -0,0 +1,88 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use derive_more::{AsMut, AsRef, Deref};
+
+use super::writer::{IndentWriter, write_debug, write_display};
+
+/// A type adaptor that indents the lines of the wrapped value's output.
+///
+/// By default, every line after the first is prefixed with the indent, so that multi-line output
+/// (an error report, a `{:#?}` dump) nested inside another message keeps its structure. Use
+/// [`Indented::all`] to indent the first line as well. The output is streamed, no allocation is
+/// performed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::Indented;
+/// let report = "error: failed\ncaused by: timeout";
+/// assert_eq!(format!("{}", Indented::new(report, "  ")), "error: failed\n  caused by: timeout");
+/// assert_eq!(format!("{}", Indented::all(report, "> ")), "> error: failed\n> caused by: timeout");
+///
+/// let nested = format!("outer: {:#?}", Indented::new(vec![1], Indented::INDENT));
+/// assert_eq!(nested, "outer: [\n        1,\n    ]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Uses the wrapped value's [`Display`] implementation, indented
+/// - **[`Debug`]**: Uses the wrapped value's [`Debug`] implementation, indented
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut)]
+pub struct Indented<'i, T> {
+    /// The wrapped value.
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub value: T,
+    /// The indent prefixed to each line.
+    pub indent: &'i str,
+    /// Whether the first line is also indented.
+    pub first_line: bool,
+}
+
+impl Indented<'static, ()> {
+    /// The default indent, four spaces, matching the standard library's pretty [`Debug`] output.
+    pub const INDENT: &'static str = "    ";
+}
+
+impl<'i, T> Indented<'i, T> {
+    /// Creates a new [`Indented`] that indents every line after the first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::Indented;
+    /// assert_eq!(format!("{}", Indented::new("a\nb", "\t")), "a\n\tb");
+    /// ```
+    #[must_use]
+    pub const fn new(value: T, indent: &'i str) -> Self {
+        Self { value, indent, first_line: false }
+    }
+
+    /// Creates a new [`Indented`] that indents every line, including the first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::Indented;
+    /// assert_eq!(format!("{}", Indented::all("a\nb", "\t")), "\ta\n\tb");
+    /// ```
+    #[must_use]
+    pub const fn all(value: T, indent: &'i str) -> Self {
+        Self { value, indent, first_line: true }
+    }
+}
+
+impl<T: Display> Display for Indented<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let alternate = f.alternate();
+        write_display(&mut IndentWriter::new(f, self.indent, self.first_line), &self.value, alternate)
+    }
+}
+
+impl<T: Debug> Debug for Indented<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let alternate = f.alternate();
+        write_debug(&mut IndentWriter::new(f, self.indent, self.first_line), &self.value, alternate)
+    }
+}
@@ -1,16 +1,44 @@
 //! Wrapper types for [`Debug`](core::fmt::Debug) and [`Display`](core::fmt::Display) formatting.
 
+mod both;
+mod bound;
+mod control_flow;
+#[cfg(feature = "alloc")]
+mod cow;
 mod debug_as_display;
 mod display_as_debug;
+mod escaped;
+mod formatted;
+mod indented;
+mod one_line;
 mod opaque;
 mod option;
+mod poll;
 mod result;
+mod strategy;
 mod type_name;
+mod writer;
 
-pub use crate::types::{DisplayMode, Full, Short};
+pub use crate::types::{DisplayMode, Full, Short, TypeNameMode};
+pub use both::Both;
+pub use bound::{BoundWith, OpaqueBound, OpaqueBoundMarker, TypeNameBound, TypeNameBoundMarker};
+pub use control_flow::{
+    ControlFlowWith, OpaqueControlFlow, OpaqueControlFlowMarker, TypeNameControlFlow, TypeNameControlFlowMarker,
+};
+#[cfg(feature = "alloc")]
+pub use cow::{CowWith, OpaqueCow, OpaqueCowMarker, TypeNameCow, TypeNameCowMarker};
 pub use debug_as_display::DebugAsDisplay;
 pub use display_as_debug::DisplayAsDebug;
+pub use escaped::{Escaped, Quoted};
+pub use formatted::{FormatAs, Formatted};
+pub use indented::Indented;
+pub use one_line::OneLine;
 pub use opaque::Opaque;
-pub use option::{OpaqueOption, OpaqueOptionMarker, TypeNameOption};
-pub use result::{OpaqueResult, OpaqueResultMarker, TypeNameResult};
+pub use option::{DisplayOption, OpaqueOption, OpaqueOptionMarker, OptionWith, Placeholder, TypeNameOption};
+pub use poll::{OpaquePoll, OpaquePollMarker, PollWith, TypeNamePoll, TypeNamePollMarker};
+pub use result::{
+    DisplayResult, FullyOpaqueResult, FullyOpaqueResultMarker, FullyTypeNameResult, OpaqueResult, OpaqueResultMarker,
+    ResultWith, TypeNameResult,
+};
+pub use strategy::{AsDebug, AsDisplay, AsIs, AsOpaque, AsTypeName, DebugStrategy, DisplayStrategy};
 pub use type_name::TypeName;
@@ -0,0 +1,46 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use derive_more::{AsMut, AsRef, Deref, From};
+
+use super::writer::{OneLineWriter, write_debug, write_display};
+
+/// A type adaptor that forces the wrapped value's output onto a single line.
+///
+/// Each newline (`\n` or `\r`) in the output, along with any indentation following it, is collapsed
+/// into a single space. Leading and trailing newlines are dropped. This keeps a record on one line,
+/// even for types that ignore the alternate flag or embed newlines in their output. The output is
+/// streamed, no allocation is performed.
+///
+/// Only line breaks are removed: quotes, tabs, other control characters and Unicode line
+/// separators are passed through unchanged, so the output is not safe to embed in a quoted logfmt
+/// value or JSON string as-is. To escape those as well, see [`Escaped`](crate::wrap::Escaped) or
+/// [`Quoted`](crate::wrap::Quoted).
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::OneLine;
+/// assert_eq!(format!("{}", OneLine("first\n    second\n")), "first second");
+/// assert_eq!(format!("{:#?}", OneLine(&vec![1, 2])), "[ 1, 2, ]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Uses the wrapped value's [`Display`] implementation, collapsed onto one line
+/// - **[`Debug`]**: Uses the wrapped value's [`Debug`] implementation, collapsed onto one line
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
+pub struct OneLine<T>(pub T);
+
+impl<T: Display> Display for OneLine<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let alternate = f.alternate();
+        write_display(&mut OneLineWriter::new(f), &self.0, alternate)
+    }
+}
+
+impl<T: Debug> Debug for OneLine<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let alternate = f.alternate();
+        write_debug(&mut OneLineWriter::new(f), &self.0, alternate)
+    }
+}
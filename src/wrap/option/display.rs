@@ -0,0 +1,53 @@
+#[cfg(doc)]
+use core::fmt::{Debug, Display};
+
+use crate::wrap::AsDisplay;
+use crate::wrap::option::OptionWith;
+
+/// A [`Option<T>`] wrapper that implements [`Debug`] using the [`Display`] implementation of the
+/// [`Some`] value.
+///
+/// Displays as `Some(value)` when the option is [`Some`], or `None` when [`None`]. Useful for
+/// showing the human readable form of values such as URLs or versions.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::DisplayOption;
+/// # use display_as_debug::types::TestValue;
+/// assert_eq!(format!("{:?}", DisplayOption(Some(TestValue(1)))), "Some(Display(1))");
+/// assert_eq!(format!("{:?}", DisplayOption(None::<TestValue>)), "None");
+///
+/// assert_eq!(format!("{}", DisplayOption(Some(TestValue(1)))), "Display(1)");
+/// assert_eq!(format!("{}", DisplayOption(None::<TestValue>)), "");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Some(value)` or `None`, using the value's [`Display`] implementation
+/// - **[`Display`]**: Shows the [`Some`] value, or an empty placeholder for [`None`] (see
+///   [`Placeholder`](crate::wrap::Placeholder))
+pub type DisplayOption<T> = OptionWith<T, AsDisplay>;
+
+/// Create a new [`DisplayOption`](type@DisplayOption) wrapper, equivalent to `OptionWith(option, AsDisplay)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn DisplayOption<T>(option: Option<T>) -> DisplayOption<T> {
+    OptionWith(option, AsDisplay)
+}
+
+impl<T> OptionWith<T, AsDisplay> {
+    /// Create a new [`DisplayOption`](type@DisplayOption) wrapper that borrows the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::DisplayOption;
+    /// let option = Some("1.2.3");
+    /// assert_eq!(format!("{:?}", DisplayOption::borrow(&option)), "Some(1.2.3)");
+    /// ```
+    #[must_use]
+    pub const fn borrow(option: &Option<T>) -> DisplayOption<&T> {
+        OptionWith(option.as_ref(), AsDisplay)
+    }
+}
@@ -1,10 +1,18 @@
 //! Option wrapper types for specialized [`Debug`](core::fmt::Debug) formatting.
 
+mod display;
 mod opaque;
+mod placeholder;
 mod type_name;
+mod with;
 
 const STR_NONE: &str = "None";
 const STR_SOME: &str = "Some";
 
+use placeholder::fmt_or;
+
+pub use display::DisplayOption;
 pub use opaque::{OpaqueOption, OpaqueOptionMarker};
+pub use placeholder::Placeholder;
 pub use type_name::TypeNameOption;
+pub use with::OptionWith;
@@ -1,9 +1,8 @@
-use core::fmt::{Debug, Formatter};
+#[cfg(doc)]
+use core::fmt::{Debug, Display};
 
-use derive_more::{AsMut, AsRef, Deref, From};
-
-use crate::fmt::DebugTupleExt;
-use crate::wrap::option::{STR_NONE, STR_SOME};
+use crate::wrap::AsOpaque;
+use crate::wrap::option::OptionWith;
 
 /// A [`Option<T>`] wrapper that implements [`Debug`] with opaque Some values.
 ///
@@ -16,14 +15,30 @@ use crate::wrap::option::{STR_NONE, STR_SOME};
 /// # use display_as_debug::wrap::OpaqueOption;
 /// assert_eq!(format!("{:?}", OpaqueOption(Some(42))), "Some(..)");
 /// assert_eq!(format!("{:?}", OpaqueOption(None::<i32>)), "None");
+///
+/// assert_eq!(format!("{}", OpaqueOption(Some(42))), "..");
+/// assert_eq!(format!("{}", OpaqueOption(None::<i32>)), "");
 /// ```
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
-pub struct OpaqueOption<T>(pub Option<T>);
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Some(..)` or `None`
+/// - **[`Display`]**: Shows `..` for [`Some`], or an empty placeholder for [`None`] (see
+///   [`Placeholder`](crate::wrap::Placeholder))
+pub type OpaqueOption<T> = OptionWith<T, AsOpaque>;
 
-/// An alias for an empty [`OpaqueOption`] that is used as an empty marker/[`Debug`] only type.
+/// Create a new [`OpaqueOption`](type@OpaqueOption) wrapper, equivalent to `OptionWith(option, AsOpaque)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn OpaqueOption<T>(option: Option<T>) -> OpaqueOption<T> {
+    OptionWith(option, AsOpaque)
+}
+
+/// An alias for an empty [`OpaqueOption`](type@OpaqueOption) that is used as an empty marker/[`Debug`] only
+/// type.
 pub type OpaqueOptionMarker = OpaqueOption<()>;
 
-impl OpaqueOption<()> {
+impl OptionWith<(), AsOpaque> {
     /// An empty marker constant for [`Some`] state.
     ///
     /// # Examples
@@ -32,7 +47,7 @@ impl OpaqueOption<()> {
     /// # use display_as_debug::wrap::OpaqueOption;
     /// assert_eq!(format!("{:?}", OpaqueOption::SOME), "Some(..)");
     /// ```
-    pub const SOME: Self = Self(Some(()));
+    pub const SOME: Self = Self(Some(()), AsOpaque);
 
     /// An empty marker constant for [`None`] state.
     ///
@@ -42,11 +57,11 @@ impl OpaqueOption<()> {
     /// # use display_as_debug::wrap::OpaqueOption;
     /// assert_eq!(format!("{:?}", OpaqueOption::NONE), "None");
     /// ```
-    pub const NONE: Self = Self(None);
+    pub const NONE: Self = Self(None, AsOpaque);
 }
 
-impl<T> OpaqueOption<T> {
-    /// Create a new [`OpaqueOption`] wrapper that borrows the wrapped value.
+impl<T> OptionWith<T, AsOpaque> {
+    /// Create a new [`OpaqueOption`](type@OpaqueOption) wrapper that borrows the wrapped value.
     ///
     /// # Examples
     ///
@@ -57,15 +72,6 @@ impl<T> OpaqueOption<T> {
     /// ```
     #[must_use]
     pub const fn borrow(option: &Option<T>) -> OpaqueOption<&T> {
-        OpaqueOption(option.as_ref())
-    }
-}
-
-impl<T> Debug for OpaqueOption<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
-            Some(_) => f.debug_tuple(STR_SOME).field_opaque().finish(),
-            None => f.write_str(STR_NONE),
-        }
+        OptionWith(option.as_ref(), AsOpaque)
     }
 }
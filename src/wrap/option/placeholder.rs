@@ -0,0 +1,48 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use super::OptionWith;
+use crate::wrap::DisplayStrategy;
+
+/// An adaptor that sets the text an [`Option`] wrapper's [`Display`] implementation uses for
+/// [`None`].
+///
+/// Useful for `-` or `n/a` cells in CLI tables. The placeholder respects width and alignment flags,
+/// as do the [`Some`] values of the wrappers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::{DisplayOption, OpaqueOption, Placeholder};
+/// assert_eq!(format!("{}", Placeholder(DisplayOption(Some(42)), "-")), "42");
+/// assert_eq!(format!("{}", Placeholder(DisplayOption(None::<i32>), "-")), "-");
+/// assert_eq!(format!("[{:>5}]", Placeholder(OpaqueOption(None::<i32>), "n/a")), "[  n/a]");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Display`]**: Uses the wrapped value's [`Display`] implementation, with the placeholder text
+///   for [`None`]
+/// - **[`Debug`]**: Uses the wrapped value's [`Debug`] implementation, ignoring the placeholder
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Placeholder<'p, W>(pub W, pub &'p str);
+
+impl<T, S: DisplayStrategy<T>> Display for Placeholder<'_, OptionWith<T, S>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_or(&self.0, self.1, f)
+    }
+}
+
+impl<W: Debug> Debug for Placeholder<'_, W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Formats `wrapper`'s [`Some`] value, or pads `placeholder` if it is [`None`].
+pub(super) fn fmt_or<T, S: DisplayStrategy<T>>(
+    wrapper: &OptionWith<T, S>,
+    placeholder: &str,
+    f: &mut Formatter<'_>,
+) -> Result {
+    wrapper.fmt_some(f).unwrap_or_else(|| f.pad(placeholder))
+}
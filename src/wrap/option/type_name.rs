@@ -1,11 +1,11 @@
-use core::fmt::{Debug, Formatter};
-use core::marker::PhantomData;
+#[cfg(doc)]
+use core::fmt::{Debug, Display};
 
-use derive_more::{AsMut, AsRef, Deref, Into};
+use core::marker::PhantomData;
 
-use crate::fmt::DebugTupleExt;
-use crate::types::{DisplayMode, Short, TypeName, TypeNameMarker};
-use crate::wrap::option::{STR_NONE, STR_SOME};
+use crate::types::{DisplayMode, Short, TypeName};
+use crate::wrap::AsTypeName;
+use crate::wrap::option::OptionWith;
 
 /// A [`Option<T>`] wrapper that implements [`Debug`], displaying type names instead of values.
 ///
@@ -27,18 +27,20 @@ use crate::wrap::option::{STR_NONE, STR_SOME};
 ///
 /// let none = TypeNameOption::new::<Full>(None::<i32>);
 /// assert_eq!(format!("{:?}", none), "None");
+///
+/// assert_eq!(format!("{}", short), "Vec<i32>");
+/// assert_eq!(format!("{}", none), "");
 /// ```
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut, Into)]
-pub struct TypeNameOption<D: ?Sized = (), T = PhantomData<fn() -> D>, M: DisplayMode = Short>(
-    #[deref]
-    #[as_ref]
-    #[as_mut]
-    pub Option<T>,
-    #[into(ignore)] pub(crate) TypeNameMarker<D, M>,
-);
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Some(typename)` or `None`
+/// - **[`Display`]**: Shows the type name for [`Some`], or an empty placeholder for [`None`] (see
+///   [`Placeholder`](crate::wrap::Placeholder))
+pub type TypeNameOption<D = (), T = PhantomData<fn() -> D>, M = Short> = OptionWith<T, AsTypeName<D, M>>;
 
 #[allow(clippy::mismatching_type_param_order, reason = "T is used for both Display and Value")]
-impl<T> TypeNameOption<T, T> {
+impl<T> OptionWith<T, AsTypeName<T>> {
     /// Create a new [`TypeNameOption`] wrapper.
     ///
     /// # Examples
@@ -56,7 +58,7 @@ impl<T> TypeNameOption<T, T> {
     /// ```
     #[must_use]
     pub const fn new<M: DisplayMode>(option: Option<T>) -> TypeNameOption<T, T, M> {
-        TypeNameOption(option, TypeName::empty())
+        OptionWith(option, TypeName::empty())
     }
 
     /// Create a new [`TypeNameOption`] wrapper that borrows the value but
@@ -79,24 +81,6 @@ impl<T> TypeNameOption<T, T> {
     /// ```
     #[must_use]
     pub const fn borrow<M: DisplayMode>(option: &Option<T>) -> TypeNameOption<T, &T, M> {
-        TypeNameOption(option.as_ref(), TypeName::empty())
-    }
-}
-
-impl<D: ?Sized, T, M: DisplayMode> Debug for TypeNameOption<D, T, M>
-where
-    TypeName<D, M>: Debug,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
-            Some(_) => f.debug_tuple(STR_SOME).field_type::<D, M>().finish(),
-            None => f.write_str(STR_NONE),
-        }
-    }
-}
-
-impl<D: ?Sized, T, M: DisplayMode> From<Option<T>> for TypeNameOption<D, T, M> {
-    fn from(option: Option<T>) -> Self {
-        Self(option, TypeName::empty())
+        OptionWith(option.as_ref(), TypeName::empty())
     }
 }
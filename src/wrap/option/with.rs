@@ -0,0 +1,68 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use derive_more::{AsMut, AsRef, Deref, Into};
+
+use crate::wrap::option::{STR_NONE, STR_SOME, fmt_or};
+use crate::wrap::strategy::{DebugStrategy, Debugged, DisplayStrategy};
+
+/// A [`Option<T>`] wrapper that formats the [`Some`] value according to the strategy `S`.
+///
+/// Any strategy implementing [`DebugStrategy`] and/or [`DisplayStrategy`] may be used, such as
+/// [`AsDebug`](crate::wrap::AsDebug), [`AsDisplay`](crate::wrap::AsDisplay),
+/// [`AsOpaque`](crate::wrap::AsOpaque), or [`AsTypeName`](crate::wrap::AsTypeName). The
+/// [`OpaqueOption`](type@crate::wrap::OpaqueOption), [`TypeNameOption`](crate::wrap::TypeNameOption),
+/// and [`DisplayOption`](type@crate::wrap::DisplayOption) wrappers are aliases of this type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::{AsDebug, AsOpaque, OptionWith};
+/// assert_eq!(format!("{:?}", OptionWith(Some("text"), AsOpaque)), "Some(..)");
+/// assert_eq!(format!("{}", OptionWith(Some("text"), AsDebug)), r#""text""#);
+/// assert_eq!(format!("{:?}", OptionWith(None::<i32>, AsOpaque)), "None");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Some(value)` or `None`, formatting the value with `S`
+/// - **[`Display`]**: Shows the [`Some`] value formatted with `S`, or an empty placeholder for
+///   [`None`] (see [`Placeholder`](crate::wrap::Placeholder))
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut, Into)]
+pub struct OptionWith<T, S>(
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub Option<T>,
+    /// The strategy used to format the [`Some`] value.
+    #[into(ignore)]
+    pub S,
+);
+
+impl<T, S: DisplayStrategy<T>> OptionWith<T, S> {
+    /// Formats the [`Some`] value into `f`, returning [`None`] (without writing anything) if the
+    /// wrapped option is [`None`].
+    pub(super) fn fmt_some(&self, f: &mut Formatter<'_>) -> Option<core::fmt::Result> {
+        self.0.as_ref().map(|value| self.1.fmt_display(value, f))
+    }
+}
+
+impl<T, S: DebugStrategy<T>> Debug for OptionWith<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Some(value) => f.debug_tuple(STR_SOME).field(&Debugged(&self.1, value)).finish(),
+            None => f.write_str(STR_NONE),
+        }
+    }
+}
+
+impl<T, S: DisplayStrategy<T>> Display for OptionWith<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_or(self, "", f)
+    }
+}
+
+impl<T, S: Default> From<Option<T>> for OptionWith<T, S> {
+    fn from(option: Option<T>) -> Self {
+        Self(option, S::default())
+    }
+}
@@ -0,0 +1,218 @@
+//! [`Poll`] wrapper types for specialized [`Debug`] formatting.
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::task::Poll;
+
+use derive_more::{AsMut, AsRef, Deref, Into};
+
+use crate::types::{DisplayMode, Full, Short, TypeName};
+use crate::wrap::strategy::{DebugStrategy, Debugged};
+use crate::wrap::{AsOpaque, AsTypeName};
+
+const STR_READY: &str = "Ready";
+const STR_PENDING: &str = "Pending";
+
+/// A [`Poll<T>`] wrapper that formats the [`Ready`](Poll::Ready) value according to the strategy
+/// `S`.
+///
+/// See [`OptionWith`](crate::wrap::OptionWith) for the available strategies.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::task::Poll;
+/// # use display_as_debug::wrap::{AsDisplay, PollWith};
+/// assert_eq!(format!("{:?}", PollWith(Poll::Ready("done"), AsDisplay)), "Ready(done)");
+/// assert_eq!(format!("{:?}", PollWith(Poll::<&str>::Pending, AsDisplay)), "Pending");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut, Into)]
+pub struct PollWith<T, S>(
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub Poll<T>,
+    /// The strategy used to format the [`Ready`](Poll::Ready) value.
+    #[into(ignore)]
+    pub S,
+);
+
+/// Borrows the value of a [`Poll`], like [`Option::as_ref`].
+const fn as_ref<T>(poll: &Poll<T>) -> Poll<&T> {
+    match poll {
+        Poll::Ready(value) => Poll::Ready(value),
+        Poll::Pending => Poll::Pending,
+    }
+}
+
+impl<T, S: DebugStrategy<T>> Debug for PollWith<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Poll::Ready(value) => f.debug_tuple(STR_READY).field(&Debugged(&self.1, value)).finish(),
+            Poll::Pending => f.write_str(STR_PENDING),
+        }
+    }
+}
+
+impl<T, S: Default> From<Poll<T>> for PollWith<T, S> {
+    fn from(poll: Poll<T>) -> Self {
+        Self(poll, S::default())
+    }
+}
+
+/// A [`Poll<T>`] wrapper that implements [`Debug`] with opaque Ready values.
+///
+/// Displays as `Ready(..)` when the poll is [`Ready`](Poll::Ready), or `Pending` when
+/// [`Pending`](Poll::Pending).
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::task::Poll;
+/// # use display_as_debug::wrap::OpaquePoll;
+/// assert_eq!(format!("{:?}", OpaquePoll(Poll::Ready(42))), "Ready(..)");
+/// assert_eq!(format!("{:?}", OpaquePoll(Poll::<i32>::Pending)), "Pending");
+/// ```
+pub type OpaquePoll<T> = PollWith<T, AsOpaque>;
+
+/// Create a new [`OpaquePoll`](type@OpaquePoll) wrapper, equivalent to `PollWith(poll, AsOpaque)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn OpaquePoll<T>(poll: Poll<T>) -> OpaquePoll<T> {
+    PollWith(poll, AsOpaque)
+}
+
+/// An alias for an empty [`OpaquePoll`](type@OpaquePoll) that is used as an empty marker/[`Debug`]
+/// only type.
+pub type OpaquePollMarker = OpaquePoll<()>;
+
+impl PollWith<(), AsOpaque> {
+    /// An empty marker constant for [`Ready`](Poll::Ready) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaquePoll;
+    /// assert_eq!(format!("{:?}", OpaquePoll::READY), "Ready(..)");
+    /// ```
+    pub const READY: Self = Self(Poll::Ready(()), AsOpaque);
+
+    /// An empty marker constant for [`Pending`](Poll::Pending) state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::OpaquePoll;
+    /// assert_eq!(format!("{:?}", OpaquePoll::PENDING), "Pending");
+    /// ```
+    pub const PENDING: Self = Self(Poll::Pending, AsOpaque);
+}
+
+impl<T> PollWith<T, AsOpaque> {
+    /// Create a new [`OpaquePoll`](type@OpaquePoll) wrapper that borrows the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::task::Poll;
+    /// # use display_as_debug::wrap::OpaquePoll;
+    /// let poll = Poll::Ready("secret");
+    /// assert_eq!(format!("{:?}", OpaquePoll::borrow(&poll)), "Ready(..)");
+    /// ```
+    #[must_use]
+    pub const fn borrow(poll: &Poll<T>) -> OpaquePoll<&T> {
+        PollWith(as_ref(poll), AsOpaque)
+    }
+}
+
+/// A [`Poll<T>`] wrapper that implements [`Debug`], displaying type names instead of values.
+///
+/// Displays as `Ready(typename)` when the poll is [`Ready`](Poll::Ready), or `Pending` when
+/// [`Pending`](Poll::Pending).
+///
+/// The type parameters match those of [`TypeNameOption`](crate::wrap::TypeNameOption).
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::task::Poll;
+/// # use display_as_debug::wrap::{Full, Short, TypeNamePoll};
+/// let short = TypeNamePoll::new::<Short>(Poll::Ready(vec![1]));
+/// assert_eq!(format!("{:?}", short), "Ready(Vec<i32>)");
+///
+/// let full = TypeNamePoll::new::<Full>(Poll::Ready(vec![1]));
+/// assert_eq!(format!("{:?}", full), "Ready(alloc::vec::Vec<i32>)");
+/// ```
+pub type TypeNamePoll<D = (), T = PhantomData<fn() -> D>, M = Short> = PollWith<T, AsTypeName<D, M>>;
+
+/// An alias for an empty [`TypeNamePoll`] that is used as a marker.
+pub type TypeNamePollMarker<D, M> = TypeNamePoll<D, PhantomData<fn() -> D>, M>;
+
+#[allow(clippy::mismatching_type_param_order, reason = "T is used for both Display and Value")]
+impl<T> PollWith<T, AsTypeName<T>> {
+    /// Create a new [`TypeNamePoll`] wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::task::Poll;
+    /// # use display_as_debug::wrap::{Short, TypeNamePoll};
+    /// let poll = TypeNamePoll::new::<Short>(Poll::Ready(1_u8));
+    /// assert_eq!(format!("{:?}", poll), "Ready(u8)");
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(poll: Poll<T>) -> TypeNamePoll<T, T, M> {
+        PollWith(poll, TypeName::empty())
+    }
+
+    /// Create a new [`TypeNamePoll`] wrapper that borrows the value but displays the inner type
+    /// name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::task::Poll;
+    /// # use display_as_debug::wrap::{Short, TypeNamePoll};
+    /// let poll = Poll::Ready(vec![1]);
+    /// assert_eq!(format!("{:?}", TypeNamePoll::borrow::<Short>(&poll)), "Ready(Vec<i32>)");
+    /// ```
+    #[must_use]
+    pub const fn borrow<M: DisplayMode>(poll: &Poll<T>) -> TypeNamePoll<T, &T, M> {
+        PollWith(as_ref(poll), TypeName::empty())
+    }
+}
+
+impl<D: ?Sized> PollWith<PhantomData<fn() -> D>, AsTypeName<D>> {
+    /// A constant for [`Pending`](Poll::Pending).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNamePoll;
+    /// assert_eq!(format!("{:?}", TypeNamePoll::<Vec<i32>>::PENDING), "Pending");
+    /// ```
+    pub const PENDING: TypeNamePollMarker<D, Full> = PollWith(Poll::Pending, TypeName::empty());
+
+    /// A constant for [`Ready`](Poll::Ready) that uses the [`Full`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNamePoll;
+    /// let ready = TypeNamePoll::<Vec<i32>>::READY_FULL;
+    /// assert_eq!(format!("{:?}", ready), "Ready(alloc::vec::Vec<i32>)");
+    /// ```
+    pub const READY_FULL: TypeNamePollMarker<D, Full> = PollWith(Poll::Ready(PhantomData), TypeName::empty());
+
+    /// A constant for [`Ready`](Poll::Ready) that uses the [`Short`] display mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::TypeNamePoll;
+    /// let ready = TypeNamePoll::<Vec<i32>>::READY_SHORT;
+    /// assert_eq!(format!("{:?}", ready), "Ready(Vec<i32>)");
+    /// ```
+    #[allow(clippy::use_self, reason = "Symmetry with READY_FULL")]
+    pub const READY_SHORT: TypeNamePollMarker<D, Short> = PollWith(Poll::Ready(PhantomData), TypeName::empty());
+}
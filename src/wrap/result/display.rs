@@ -0,0 +1,54 @@
+#[cfg(doc)]
+use core::fmt::{Debug, Display};
+
+use crate::wrap::AsDisplay;
+use crate::wrap::result::ResultWith;
+
+/// A [`Result<T, E>`] wrapper that implements [`Debug`] using the [`Display`] implementations of
+/// both the [`Ok`] and [`Err`] values.
+///
+/// Displays as `Ok(value)` when the result is [`Ok`], or `Err(error)` when [`Err`]. Useful for
+/// showing the human readable form of values and errors.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::DisplayResult;
+/// # use display_as_debug::types::TestValue;
+/// let ok = Ok::<_, TestValue>(TestValue(1));
+/// assert_eq!(format!("{:?}", DisplayResult(ok)), "Ok(Display(1))");
+///
+/// let err = Err::<TestValue, _>(TestValue("failed"));
+/// assert_eq!(format!("{:?}", DisplayResult(err)), r#"Err(Display("failed"))"#);
+/// assert_eq!(format!("{}", DisplayResult(err)), r#"Display("failed")"#);
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Ok(value)` or `Err(error)`, using their [`Display`] implementations
+/// - **[`Display`]**: Shows the [`Ok`] or [`Err`] value's [`Display`] implementation
+pub type DisplayResult<T, E> = ResultWith<T, E, AsDisplay, AsDisplay>;
+
+/// Create a new [`DisplayResult`](type@DisplayResult) wrapper, equivalent to
+/// `ResultWith(result, AsDisplay, AsDisplay)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn DisplayResult<T, E>(result: Result<T, E>) -> DisplayResult<T, E> {
+    ResultWith(result, AsDisplay, AsDisplay)
+}
+
+impl<T, E> ResultWith<T, E, AsDisplay, AsDisplay> {
+    /// Create a new [`DisplayResult`](type@DisplayResult) wrapper that borrows the wrapped value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::DisplayResult;
+    /// let res: Result<&str, &str> = Err("connection refused");
+    /// assert_eq!(format!("{:?}", DisplayResult::borrow(&res)), "Err(connection refused)");
+    /// ```
+    #[must_use]
+    pub const fn borrow(result: &Result<T, E>) -> DisplayResult<&T, &E> {
+        ResultWith(result.as_ref(), AsDisplay, AsDisplay)
+    }
+}
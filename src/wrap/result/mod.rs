@@ -1,10 +1,14 @@
 //! Result wrapper types for specialized [`Debug`](core::fmt::Debug) formatting.
 
+mod display;
 mod opaque;
 mod type_name;
+mod with;
 
 const STR_ERR: &str = "Err";
 const STR_OK: &str = "Ok";
 
-pub use opaque::{OpaqueResult, OpaqueResultMarker};
-pub use type_name::TypeNameResult;
+pub use display::DisplayResult;
+pub use opaque::{FullyOpaqueResult, FullyOpaqueResultMarker, OpaqueResult, OpaqueResultMarker};
+pub use type_name::{FullyTypeNameResult, TypeNameResult};
+pub use with::ResultWith;
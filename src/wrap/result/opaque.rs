@@ -1,33 +1,50 @@
-use core::fmt::{Debug, Formatter};
+#[cfg(doc)]
+use core::fmt::{Debug, Display};
 
-use derive_more::{AsMut, AsRef, Deref, From};
-
-use crate::fmt::DebugTupleExt;
-use crate::wrap::result::{STR_ERR, STR_OK};
+use crate::wrap::result::ResultWith;
+use crate::wrap::{AsIs, AsOpaque};
 
 /// A [`Result<T, E>`] wrapper that implements [`Debug`] with opaque Ok values.
 ///
 /// Displays as `Ok(..)` when the result is [`Ok`], or `Err(error_value)` when [`Err`].
 /// This provides privacy for Ok values while fully debugging errors.
 ///
+/// To obscure the [`Err`] value as well, see [`FullyOpaqueResult`](type@FullyOpaqueResult).
+///
 /// # Examples
 ///
 /// ```rust
 /// # use display_as_debug::wrap::OpaqueResult;
 /// assert_eq!(format!("{:?}", OpaqueResult(Ok::<_, &str>(42))), "Ok(..)");
 /// assert_eq!(format!("{:?}", OpaqueResult(Err::<i32, &str>("fail"))), r#"Err("fail")"#);
+///
+/// assert_eq!(format!("{}", OpaqueResult(Ok::<_, &str>(42))), "..");
+/// assert_eq!(format!("{}", OpaqueResult(Err::<i32, &str>("fail"))), "fail");
 /// ```
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From, Deref, AsRef, AsMut)]
-pub struct OpaqueResult<T, E>(pub Result<T, E>);
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Ok(..)`, or `Err(error)` using the error's [`Debug`] implementation
+/// - **[`Display`]**: Shows `..` for [`Ok`], or the error's [`Display`] implementation for [`Err`]
+pub type OpaqueResult<T, E> = ResultWith<T, E, AsOpaque, AsIs>;
 
-/// An alias for an empty [`OpaqueResult`] in the [`Ok`] state that is used as an empty
-/// marker/[`Debug`] only type.
+/// Create a new [`OpaqueResult`](type@OpaqueResult) wrapper, equivalent to
+/// `ResultWith(result, AsOpaque, AsIs)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn OpaqueResult<T, E>(result: Result<T, E>) -> OpaqueResult<T, E> {
+    ResultWith(result, AsOpaque, AsIs)
+}
+
+/// An alias for an empty [`OpaqueResult`](type@OpaqueResult) in the [`Ok`] state that is used as an
+/// empty marker/[`Debug`] only type.
 ///
-/// Note, that because the [`Err`] variant of [`OpaqueResult`] is not empty
-/// (it displays the error value), a marker type can only represent the [`Ok`] variant.
+/// Note, that because the [`Err`] variant of [`OpaqueResult`](type@OpaqueResult) is not empty
+/// (it displays the error value), a marker type can only represent the [`Ok`] variant. See
+/// [`FullyOpaqueResultMarker`] for a marker that can also represent the [`Err`] variant.
 pub type OpaqueResultMarker = OpaqueResult<(), ()>;
 
-impl OpaqueResult<(), ()> {
+impl ResultWith<(), (), AsOpaque, AsIs> {
     /// An empty marker constant for [`Ok`] state.
     ///
     /// # Examples
@@ -36,11 +53,11 @@ impl OpaqueResult<(), ()> {
     /// # use display_as_debug::wrap::OpaqueResult;
     /// assert_eq!(format!("{:?}", OpaqueResult::OK), "Ok(..)");
     /// ```
-    pub const OK: Self = Self(Ok(()));
+    pub const OK: Self = Self(Ok(()), AsOpaque, AsIs);
 }
 
-impl<T, E> OpaqueResult<T, E> {
-    /// Create a new [`OpaqueResult`] wrapper that borrows the wrapped value.
+impl<T, E> ResultWith<T, E, AsOpaque, AsIs> {
+    /// Create a new [`OpaqueResult`](type@OpaqueResult) wrapper that borrows the wrapped value.
     ///
     /// # Examples
     ///
@@ -51,15 +68,79 @@ impl<T, E> OpaqueResult<T, E> {
     /// ```
     #[must_use]
     pub const fn borrow(option: &Result<T, E>) -> OpaqueResult<&T, &E> {
-        OpaqueResult(option.as_ref())
+        ResultWith(option.as_ref(), AsOpaque, AsIs)
     }
 }
 
-impl<T, E: Debug> Debug for OpaqueResult<T, E> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
-            Ok(_) => f.debug_tuple(STR_OK).field_opaque().finish(),
-            Err(e) => f.debug_tuple(STR_ERR).field(e).finish(),
-        }
+/// A [`Result<T, E>`] wrapper that implements [`Debug`] and [`Display`] with opaque Ok and Err
+/// values.
+///
+/// Displays as `Ok(..)` when the result is [`Ok`], or `Err(..)` when [`Err`]. Unlike
+/// [`OpaqueResult`](type@OpaqueResult), the error is never shown, so `E` need not implement
+/// [`Debug`] and error payloads carrying user data cannot leak.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::FullyOpaqueResult;
+/// assert_eq!(format!("{:?}", FullyOpaqueResult(Ok::<_, &str>(42))), "Ok(..)");
+/// assert_eq!(format!("{:?}", FullyOpaqueResult(Err::<i32, &str>("token"))), "Err(..)");
+/// assert_eq!(format!("{}", FullyOpaqueResult(Err::<i32, &str>("token"))), "..");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Ok(..)` or `Err(..)`
+/// - **[`Display`]**: Shows `..`
+pub type FullyOpaqueResult<T, E> = ResultWith<T, E, AsOpaque, AsOpaque>;
+
+/// Create a new [`FullyOpaqueResult`](type@FullyOpaqueResult) wrapper, equivalent to
+/// `ResultWith(result, AsOpaque, AsOpaque)`.
+#[allow(non_snake_case, reason = "Mirrors a tuple struct constructor")]
+#[must_use]
+pub const fn FullyOpaqueResult<T, E>(result: Result<T, E>) -> FullyOpaqueResult<T, E> {
+    ResultWith(result, AsOpaque, AsOpaque)
+}
+
+/// An alias for an empty [`FullyOpaqueResult`](type@FullyOpaqueResult) that is used as an empty
+/// marker/[`Debug`] only type, for either the [`Ok`] or [`Err`] state.
+pub type FullyOpaqueResultMarker = FullyOpaqueResult<(), ()>;
+
+impl ResultWith<(), (), AsOpaque, AsOpaque> {
+    /// An empty marker constant for [`Ok`] state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::FullyOpaqueResult;
+    /// assert_eq!(format!("{:?}", FullyOpaqueResult::OK), "Ok(..)");
+    /// ```
+    pub const OK: Self = Self(Ok(()), AsOpaque, AsOpaque);
+
+    /// An empty marker constant for [`Err`] state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::FullyOpaqueResult;
+    /// assert_eq!(format!("{:?}", FullyOpaqueResult::ERR), "Err(..)");
+    /// ```
+    pub const ERR: Self = Self(Err(()), AsOpaque, AsOpaque);
+}
+
+impl<T, E> ResultWith<T, E, AsOpaque, AsOpaque> {
+    /// Create a new [`FullyOpaqueResult`](type@FullyOpaqueResult) wrapper that borrows the wrapped
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::FullyOpaqueResult;
+    /// let res: Result<&str, &str> = Err("secret");
+    /// assert_eq!(format!("{:?}", FullyOpaqueResult::borrow(&res)), "Err(..)");
+    /// ```
+    #[must_use]
+    pub const fn borrow(result: &Result<T, E>) -> FullyOpaqueResult<&T, &E> {
+        ResultWith(result.as_ref(), AsOpaque, AsOpaque)
     }
 }
@@ -1,15 +1,15 @@
-use core::fmt::{Debug, Formatter};
+#[cfg(doc)]
+use core::fmt::{Debug, Display};
 
-use derive_more::{AsMut, AsRef, Deref, Into};
-
-use crate::fmt::DebugTupleExt;
-use crate::types::{DisplayMode, Full, TypeName, TypeNameMarker};
-use crate::wrap::result::{STR_ERR, STR_OK};
+use crate::types::{DisplayMode, Full, TypeName};
+use crate::wrap::result::ResultWith;
+use crate::wrap::{AsIs, AsTypeName};
 
 /// A [`Result<T, E>`] wrapper that implements [`Debug`], displaying type names instead of values.
 ///
 /// Displays as `"Ok(typename)"` when the result is [`Ok`], for [`Err`] the [`Debug`]
-/// implementation of `E` is used.
+/// implementation of `E` is used. To show the type name of `E` instead, see
+/// [`FullyTypeNameResult`].
 ///
 /// The `M` type parameter controls whether [`Full`](crate::types::Full) or [`Short`](crate::types::Short)
 /// type names are displayed.
@@ -37,17 +37,20 @@ use crate::wrap::result::{STR_ERR, STR_OK};
 ///
 /// let err = Err::<i32, &str>("error");
 /// assert_eq!(format!("{:?}", TypeNameResult::new::<Full>(err)), r#"Err("error")"#);
+///
+/// assert_eq!(format!("{}", short), "Vec<i32>");
+/// assert_eq!(format!("{}", TypeNameResult::new::<Full>(err)), "error");
 /// ```
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut, Into)]
-pub struct TypeNameResult<T, E = (), D: ?Sized = T, M: DisplayMode = Full>(
-    #[deref]
-    #[as_ref]
-    #[as_mut]
-    pub Result<T, E>,
-    #[into(ignore)] pub(crate) TypeNameMarker<D, M>,
-);
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Ok(typename)`, or `Err(error)` using the error's
+///   [`Debug`] implementation
+/// - **[`Display`]**: Shows the type name for [`Ok`], or the error's
+///   [`Display`] implementation for [`Err`]
+pub type TypeNameResult<T, E = (), D = T, M = Full> = ResultWith<T, E, AsTypeName<D, M>, AsIs>;
 
-impl<T, E> TypeNameResult<T, E> {
+impl<T, E> ResultWith<T, E, AsTypeName<T, Full>, AsIs> {
     /// Create a new [`TypeNameResult`] wrapper.
     ///
     /// # Examples
@@ -63,7 +66,7 @@ impl<T, E> TypeNameResult<T, E> {
     /// ```
     #[must_use]
     pub const fn new<M: DisplayMode>(result: Result<T, E>) -> TypeNameResult<T, E, T, M> {
-        TypeNameResult(result, TypeName::empty())
+        ResultWith(result, TypeName::empty(), AsIs)
     }
 
     /// Create a new [`TypeNameResult`] wrapper that borrows the value but displays the inner type name.
@@ -81,25 +84,76 @@ impl<T, E> TypeNameResult<T, E> {
     /// ```
     #[must_use]
     pub const fn borrow<M: DisplayMode>(result: &Result<T, E>) -> TypeNameResult<&T, &E, T, M> {
-        TypeNameResult(result.as_ref(), TypeName::empty())
+        ResultWith(result.as_ref(), TypeName::empty(), AsIs)
     }
 }
 
-impl<T, E: Debug, D: ?Sized, M: DisplayMode> Debug for TypeNameResult<T, E, D, M>
-where
-    TypeName<D, M>: Debug,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
-            Ok(_) => f.debug_tuple(STR_OK).field_type::<D, M>().finish(),
-            Err(e) => f.debug_tuple(STR_ERR).field(e).finish(),
-        }
+/// A [`Result<T, E>`] wrapper that implements [`Debug`] and [`Display`], displaying type names
+/// instead of values for both the [`Ok`] and [`Err`] variants.
+///
+/// Displays as `"Ok(typename)"` when the result is [`Ok`], or `"Err(typename)"` when [`Err`].
+/// Unlike [`TypeNameResult`], `E` need not implement [`Debug`], and error payloads carrying user
+/// data cannot leak.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the value in the [`Ok`] variant.
+/// - `E`: The type of the value in the [`Err`] variant.
+/// - `DT`: The type that is displayed in the [`Ok`] variant.
+/// - `DE`: The type that is displayed in the [`Err`] variant.
+///   - Both are used internally to display the type names as `T` and `E` when borrowing a
+///     [`Result`].
+/// - `M`: The display mode for the type names.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::FullyTypeNameResult;
+/// # use display_as_debug::types::{Full, Short};
+/// let ok = Ok::<_, core::fmt::Error>(vec![1]);
+/// assert_eq!(format!("{:?}", FullyTypeNameResult::new::<Short>(ok)), "Ok(Vec<i32>)");
+///
+/// let err = Err::<i32, _>(core::fmt::Error);
+/// assert_eq!(format!("{:?}", FullyTypeNameResult::new::<Short>(err)), "Err(Error)");
+/// assert_eq!(format!("{}", FullyTypeNameResult::new::<Full>(err)), "core::fmt::Error");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Ok(typename)` or `Err(typename)`
+/// - **[`Display`]**: Shows the type name of the [`Ok`] or [`Err`] value
+pub type FullyTypeNameResult<T, E, DT = T, DE = E, M = Full> = ResultWith<T, E, AsTypeName<DT, M>, AsTypeName<DE, M>>;
+
+impl<T, E> ResultWith<T, E, AsTypeName<T, Full>, AsTypeName<E, Full>> {
+    /// Create a new [`FullyTypeNameResult`] wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::FullyTypeNameResult;
+    /// # use display_as_debug::types::Full;
+    /// let err = Err::<i32, &str>("token");
+    /// assert_eq!(format!("{:?}", FullyTypeNameResult::new::<Full>(err)), "Err(&str)");
+    /// ```
+    #[must_use]
+    pub const fn new<M: DisplayMode>(result: Result<T, E>) -> FullyTypeNameResult<T, E, T, E, M> {
+        ResultWith(result, TypeName::empty(), TypeName::empty())
     }
-}
 
-impl<T, E, D: ?Sized, M: DisplayMode> From<Result<T, E>> for TypeNameResult<T, E, D, M> {
-    fn from(result: Result<T, E>) -> Self {
-        Self(result, TypeName::empty())
+    /// Create a new [`FullyTypeNameResult`] wrapper that borrows the value but displays the inner
+    /// type names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::FullyTypeNameResult;
+    /// # use display_as_debug::types::Short;
+    /// let err = Err::<i32, core::fmt::Error>(core::fmt::Error);
+    /// assert_eq!(format!("{:?}", FullyTypeNameResult::borrow::<Short>(&err)), "Err(Error)");
+    /// ```
+    #[must_use]
+    pub const fn borrow<M: DisplayMode>(result: &Result<T, E>) -> FullyTypeNameResult<&T, &E, T, E, M> {
+        ResultWith(result.as_ref(), TypeName::empty(), TypeName::empty())
     }
 }
 
@@ -0,0 +1,107 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use derive_more::{AsMut, AsRef, Deref, Into};
+
+use crate::wrap::result::{STR_ERR, STR_OK};
+use crate::wrap::strategy::{DebugStrategy, Debugged, DisplayStrategy};
+
+/// A [`Result<T, E>`] wrapper that formats the [`Ok`] value according to the strategy `SOk`, and
+/// the [`Err`] value according to the strategy `SErr`.
+///
+/// Any strategy implementing [`DebugStrategy`] and/or [`DisplayStrategy`] may be used, such as
+/// [`AsIs`](crate::wrap::AsIs), [`AsDebug`](crate::wrap::AsDebug),
+/// [`AsDisplay`](crate::wrap::AsDisplay), [`AsOpaque`](crate::wrap::AsOpaque), or
+/// [`AsTypeName`](crate::wrap::AsTypeName). The [`OpaqueResult`](type@crate::wrap::OpaqueResult),
+/// [`TypeNameResult`](crate::wrap::TypeNameResult), and
+/// [`DisplayResult`](type@crate::wrap::DisplayResult) wrappers are aliases of this type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::wrap::{AsDisplay, AsOpaque, ResultWith, Short, TypeName};
+/// let ok = ResultWith(Ok::<_, &str>(42), AsOpaque, AsDisplay);
+/// assert_eq!(format!("{:?}", ok), "Ok(..)");
+///
+/// let err = ResultWith(Err::<i32, _>("fail"), AsOpaque, AsDisplay);
+/// assert_eq!(format!("{:?}", err), "Err(fail)");
+///
+/// let err = ResultWith(Err::<i32, _>("fail"), AsOpaque, TypeName::empty::<&str, Short>());
+/// assert_eq!(format!("{:?}", err), "Err(&str)");
+/// ```
+///
+/// # Notable Trait Implementations
+///
+/// - **[`Debug`]**: Shows `Ok(value)` or `Err(error)`, formatting the value with `SOk` or the
+///   error with `SErr`
+/// - **[`Display`]**: Shows the [`Ok`] value formatted with `SOk`, or the [`Err`] value formatted
+///   with `SErr`
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, AsRef, AsMut, Into)]
+pub struct ResultWith<T, E, SOk, SErr>(
+    #[deref]
+    #[as_ref]
+    #[as_mut]
+    pub Result<T, E>,
+    /// The strategy used to format the [`Ok`] value.
+    #[into(ignore)]
+    pub SOk,
+    /// The strategy used to format the [`Err`] value.
+    #[into(ignore)]
+    pub SErr,
+);
+
+impl<T, E, SOk, SErr> ResultWith<T, E, SOk, SErr> {
+    /// Replaces the strategy used to format the [`Ok`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::{AsOpaque, DisplayResult};
+    /// let ok = DisplayResult(Ok::<_, &str>("secret")).with_ok(AsOpaque);
+    /// assert_eq!(format!("{:?}", ok), "Ok(..)");
+    /// ```
+    #[must_use]
+    pub fn with_ok<S>(self, strategy: S) -> ResultWith<T, E, S, SErr> {
+        ResultWith(self.0, strategy, self.2)
+    }
+
+    /// Replaces the strategy used to format the [`Err`] value.
+    ///
+    /// Useful to hide the error payload of an existing wrapper, for example when it may carry user
+    /// data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::wrap::{AsOpaque, OpaqueResult};
+    /// let err = OpaqueResult(Err::<i32, _>("rejected token: hunter2")).with_err(AsOpaque);
+    /// assert_eq!(format!("{:?}", err), "Err(..)");
+    /// ```
+    #[must_use]
+    pub fn with_err<S>(self, strategy: S) -> ResultWith<T, E, SOk, S> {
+        ResultWith(self.0, self.1, strategy)
+    }
+}
+
+impl<T, E, SOk: DebugStrategy<T>, SErr: DebugStrategy<E>> Debug for ResultWith<T, E, SOk, SErr> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Ok(value) => f.debug_tuple(STR_OK).field(&Debugged(&self.1, value)).finish(),
+            Err(e) => f.debug_tuple(STR_ERR).field(&Debugged(&self.2, e)).finish(),
+        }
+    }
+}
+
+impl<T, E, SOk: DisplayStrategy<T>, SErr: DisplayStrategy<E>> Display for ResultWith<T, E, SOk, SErr> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Ok(value) => self.1.fmt_display(value, f),
+            Err(e) => self.2.fmt_display(e, f),
+        }
+    }
+}
+
+impl<T, E, SOk: Default, SErr: Default> From<Result<T, E>> for ResultWith<T, E, SOk, SErr> {
+    fn from(result: Result<T, E>) -> Self {
+        Self(result, SOk::default(), SErr::default())
+    }
+}
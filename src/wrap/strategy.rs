@@ -0,0 +1,142 @@
+//! Per-value formatting strategies used by [`OptionWith`](crate::wrap::OptionWith) and
+//! [`ResultWith`](crate::wrap::ResultWith).
+
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::marker::PhantomData;
+
+use crate::types::{DisplayMode, OPAQUE, Opaque, Short, TypeName, TypeNameMarker};
+
+/// A strategy for formatting a value of type `T` in a [`Debug`] context.
+///
+/// Implemented by [`AsIs`], [`AsDebug`], [`AsDisplay`], [`AsOpaque`], and [`AsTypeName`]. Custom
+/// strategies may also be implemented.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::fmt::{Formatter, Result};
+/// # use display_as_debug::wrap::{DebugStrategy, OptionWith};
+/// #[derive(Copy, Clone)]
+/// struct Len;
+///
+/// impl DebugStrategy<&str> for Len {
+///     fn fmt_debug(&self, value: &&str, f: &mut Formatter<'_>) -> Result {
+///         write!(f, "{} bytes", value.len())
+///     }
+/// }
+///
+/// assert_eq!(format!("{:?}", OptionWith(Some("secret"), Len)), "Some(6 bytes)");
+/// ```
+pub trait DebugStrategy<T: ?Sized> {
+    /// Formats `value` according to this strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `f` fails.
+    fn fmt_debug(&self, value: &T, f: &mut Formatter<'_>) -> Result;
+}
+
+/// A strategy for formatting a value of type `T` in a [`Display`] context.
+///
+/// Implemented by [`AsIs`], [`AsDebug`], [`AsDisplay`], [`AsOpaque`], and [`AsTypeName`]. Custom
+/// strategies may also be implemented.
+pub trait DisplayStrategy<T: ?Sized> {
+    /// Formats `value` according to this strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `f` fails.
+    fn fmt_display(&self, value: &T, f: &mut Formatter<'_>) -> Result;
+}
+
+/// Strategy that formats values using their own [`Debug`] implementation in [`Debug`] contexts,
+/// and their own [`Display`] implementation in [`Display`] contexts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsIs;
+
+impl<T: Debug + ?Sized> DebugStrategy<T> for AsIs {
+    fn fmt_debug(&self, value: &T, f: &mut Formatter<'_>) -> Result {
+        value.fmt(f)
+    }
+}
+
+impl<T: Display + ?Sized> DisplayStrategy<T> for AsIs {
+    fn fmt_display(&self, value: &T, f: &mut Formatter<'_>) -> Result {
+        value.fmt(f)
+    }
+}
+
+/// Strategy that formats values using their [`Debug`] implementation, in all contexts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsDebug;
+
+impl<T: Debug + ?Sized> DebugStrategy<T> for AsDebug {
+    fn fmt_debug(&self, value: &T, f: &mut Formatter<'_>) -> Result {
+        value.fmt(f)
+    }
+}
+
+impl<T: Debug + ?Sized> DisplayStrategy<T> for AsDebug {
+    fn fmt_display(&self, value: &T, f: &mut Formatter<'_>) -> Result {
+        value.fmt(f)
+    }
+}
+
+/// Strategy that formats values using their [`Display`] implementation, in all contexts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsDisplay;
+
+impl<T: Display + ?Sized> DebugStrategy<T> for AsDisplay {
+    fn fmt_debug(&self, value: &T, f: &mut Formatter<'_>) -> Result {
+        value.fmt(f)
+    }
+}
+
+impl<T: Display + ?Sized> DisplayStrategy<T> for AsDisplay {
+    fn fmt_display(&self, value: &T, f: &mut Formatter<'_>) -> Result {
+        value.fmt(f)
+    }
+}
+
+/// Strategy that obscures values, formatting them as `..`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsOpaque;
+
+impl<T: ?Sized> DebugStrategy<T> for AsOpaque {
+    fn fmt_debug(&self, _: &T, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&OPAQUE, f)
+    }
+}
+
+impl<T: ?Sized> DisplayStrategy<T> for AsOpaque {
+    fn fmt_display(&self, _: &T, f: &mut Formatter<'_>) -> Result {
+        f.pad(Opaque::<()>::OPAQUE_STR)
+    }
+}
+
+/// Strategy that formats values as the type name of `D`, using the display mode `M`.
+///
+/// `D` is usually the type of the value, but may differ, for example to show `T` rather than `&T`
+/// when borrowing.
+pub type AsTypeName<D, M = Short> = TypeNameMarker<D, M>;
+
+impl<T: ?Sized, D: ?Sized, M: DisplayMode> DebugStrategy<T> for TypeName<D, PhantomData<fn() -> D>, M> {
+    fn fmt_debug(&self, _: &T, f: &mut Formatter<'_>) -> Result {
+        f.write_str(M::type_name::<D>())
+    }
+}
+
+impl<T: ?Sized, D: ?Sized, M: DisplayMode> DisplayStrategy<T> for TypeName<D, PhantomData<fn() -> D>, M> {
+    fn fmt_display(&self, _: &T, f: &mut Formatter<'_>) -> Result {
+        f.pad(M::type_name::<D>())
+    }
+}
+
+/// Formats a value according to a [`DebugStrategy`], for use in debug builders.
+pub(super) struct Debugged<'a, S, T: ?Sized>(pub(super) &'a S, pub(super) &'a T);
+
+impl<S: DebugStrategy<T>, T: ?Sized> Debug for Debugged<'_, S, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt_debug(self.1, f)
+    }
+}
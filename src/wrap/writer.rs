@@ -0,0 +1,115 @@
+//! Streaming [`Write`] adapters used by the wrapper types.
+
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+
+/// Writes `value`'s [`Display`] output to `writer`, forwarding the alternate (`#`) flag.
+pub(super) fn write_display<W: Write>(writer: &mut W, value: &dyn Display, alternate: bool) -> Result {
+    if alternate { write!(writer, "{value:#}") } else { write!(writer, "{value}") }
+}
+
+/// Writes `value`'s [`Debug`] output to `writer`, forwarding the alternate (`#`) flag.
+pub(super) fn write_debug<W: Write>(writer: &mut W, value: &dyn Debug, alternate: bool) -> Result {
+    if alternate { write!(writer, "{value:#?}") } else { write!(writer, "{value:?}") }
+}
+
+/// A [`Write`] adapter that escapes everything written through it, as by [`char::escape_debug`].
+pub(super) struct EscapeWriter<'a, 'f> {
+    f: &'a mut Formatter<'f>,
+    /// If `true`, single quotes are left unescaped, matching the [`Debug`]
+    /// output of [`str`].
+    quoted: bool,
+}
+
+impl<'a, 'f> EscapeWriter<'a, 'f> {
+    pub(super) const fn new(f: &'a mut Formatter<'f>, quoted: bool) -> Self {
+        Self { f, quoted }
+    }
+}
+
+impl Write for EscapeWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut start = 0;
+        for (index, c) in s.char_indices().filter(|&(_, c)| !(self.quoted && c == '\'')) {
+            let escaped = c.escape_debug();
+            if escaped.len() != 1 {
+                self.f.write_str(&s[start..index])?;
+                Display::fmt(&escaped, self.f)?;
+                start = index + c.len_utf8();
+            }
+        }
+        self.f.write_str(&s[start..])
+    }
+}
+
+/// A [`Write`] adapter that collapses each newline, along with any whitespace following it, into a
+/// single space.
+///
+/// Leading and trailing newlines are dropped entirely.
+pub(super) struct OneLineWriter<'a, 'f> {
+    f: &'a mut Formatter<'f>,
+    /// Whether any output has been written yet.
+    started: bool,
+    /// Whether a newline has been seen and its trailing whitespace is being skipped.
+    newline: bool,
+}
+
+impl<'a, 'f> OneLineWriter<'a, 'f> {
+    pub(super) const fn new(f: &'a mut Formatter<'f>) -> Self {
+        Self { f, started: false, newline: false }
+    }
+}
+
+impl Write for OneLineWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let mut start = 0;
+        for (index, c) in s.char_indices() {
+            match c {
+                '\n' | '\r' => {
+                    self.f.write_str(&s[start..index])?;
+                    self.newline = true;
+                    start = index + 1;
+                }
+                c if self.newline && c.is_whitespace() => start = index + c.len_utf8(),
+                _ if self.newline => {
+                    if self.started {
+                        self.f.write_char(' ')?;
+                    }
+                    self.newline = false;
+                    self.started = true;
+                }
+                _ => self.started = true,
+            }
+        }
+        self.f.write_str(&s[start..])
+    }
+}
+
+/// A [`Write`] adapter that prefixes lines with an indent, like the standard library's internal
+/// `PadAdapter`.
+pub(super) struct IndentWriter<'a, 'f> {
+    f: &'a mut Formatter<'f>,
+    indent: &'a str,
+    /// Whether the next write starts a new line, and so must be indented.
+    newline: bool,
+}
+
+impl<'a, 'f> IndentWriter<'a, 'f> {
+    /// Creates a new [`IndentWriter`]. If `first_line` is `false`, only the lines after the
+    /// first are indented.
+    pub(super) const fn new(f: &'a mut Formatter<'f>, indent: &'a str, first_line: bool) -> Self {
+        Self { f, indent, newline: first_line }
+    }
+}
+
+impl Write for IndentWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for line in s.split_inclusive('\n') {
+            if self.newline {
+                self.f.write_str(self.indent)?;
+            }
+            self.newline = line.ends_with('\n');
+            self.f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+use core::fmt::{Arguments, Debug, Display, Error, Formatter, Result, Write};
+
+/// How a [`FmtBuf`] handles writes that overflow its capacity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Overflow {
+    /// Writes that do not fit fail with [`Error`], leaving the buffer holding everything written
+    /// before the failing write.
+    Error,
+    /// Writes that do not fit are truncated, and the buffer is ended with the given marker.
+    /// Further writes are discarded, and all writes succeed.
+    Truncate(&'static str),
+}
+
+/// A fixed-capacity stack buffer implementing [`Write`], for formatting without allocation.
+///
+/// Enables formatted output to be turned into a [`str`] in `no_std` environments without `alloc`,
+/// for sending over a serial line or storing in a fixed-size record. Overflow is handled according
+/// to the buffer's [`Overflow`] mode.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::write::FmtBuf;
+/// # use display_as_debug::wrap::OpaqueOption;
+/// use core::fmt::Write;
+///
+/// let mut buf = FmtBuf::<16>::new();
+/// write!(buf, "{:?}", OpaqueOption(Some("secret"))).unwrap();
+/// assert_eq!(buf.as_str(), "Some(..)");
+///
+/// let mut buf = FmtBuf::<4>::new();
+/// assert!(write!(buf, "{}", 123456).is_err(), "write should overflow");
+///
+/// let mut buf = FmtBuf::<8>::truncating("...");
+/// write!(buf, "{}", "a long message").unwrap();
+/// assert_eq!(buf.as_str(), "a lon...");
+/// ```
+#[derive(Clone)]
+pub struct FmtBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    overflow: Overflow,
+    overflowed: bool,
+}
+
+impl<const N: usize> FmtBuf<N> {
+    /// Creates a new, empty [`FmtBuf`] that fails writes that overflow with [`Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::FmtBuf;
+    /// use core::fmt::Write;
+    ///
+    /// let mut buf = FmtBuf::<4>::new();
+    /// write!(buf, "{}", 12).unwrap();
+    /// assert!(write!(buf, "{}", 345).is_err(), "write should overflow");
+    /// assert_eq!(buf.as_str(), "12");
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_overflow(Overflow::Error)
+    }
+
+    /// Creates a new, empty [`FmtBuf`] that truncates writes that overflow, ending the buffer
+    /// with `marker`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::FmtBuf;
+    /// use core::fmt::Write;
+    ///
+    /// let mut buf = FmtBuf::<6>::truncating("~");
+    /// write!(buf, "{}", 1234567).unwrap();
+    /// assert_eq!(buf.as_str(), "12345~");
+    /// assert!(buf.is_overflowed());
+    /// ```
+    #[must_use]
+    pub const fn truncating(marker: &'static str) -> Self {
+        Self::with_overflow(Overflow::Truncate(marker))
+    }
+
+    /// Creates a new, empty [`FmtBuf`] with the given [`Overflow`] mode.
+    #[must_use]
+    pub const fn with_overflow(overflow: Overflow) -> Self {
+        Self { buf: [0; N], len: 0, overflow, overflowed: false }
+    }
+
+    /// Returns the contents of the buffer.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // the buffer only ever holds whole `str` writes, split on char boundaries
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+
+    /// Returns the length of the contents of the buffer, in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the capacity of the buffer, in bytes.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if a write has overflowed the buffer.
+    #[must_use]
+    pub const fn is_overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Clears the buffer, resetting its overflow state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::FmtBuf;
+    /// use core::fmt::Write;
+    ///
+    /// let mut buf = FmtBuf::<2>::truncating("");
+    /// write!(buf, "{}", 123).unwrap();
+    /// buf.clear();
+    /// assert!(buf.is_empty() && !buf.is_overflowed());
+    /// ```
+    pub const fn clear(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+    }
+
+    /// Truncates `s` to fit, and ends the buffer with `marker`.
+    fn truncate(&mut self, s: &str, marker: &str) {
+        let marker = prefix(marker, N);
+        let available = N - marker.len();
+        self.len = prefix(self.as_str(), available).len();
+        let s = prefix(s, available - self.len);
+        self.push(s);
+        self.push(marker);
+        self.overflowed = true;
+    }
+
+    /// Appends `s`, which must fit.
+    fn push(&mut self, s: &str) {
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+    }
+}
+
+impl<const N: usize> Write for FmtBuf<N> {
+    fn write_str(&mut self, s: &str) -> Result {
+        match self.overflow {
+            Overflow::Truncate(_) if self.overflowed => Ok(()),
+            _ if s.len() <= N - self.len => {
+                self.push(s);
+                Ok(())
+            }
+            Overflow::Error => {
+                self.overflowed = true;
+                Err(Error)
+            }
+            Overflow::Truncate(marker) => {
+                self.truncate(s, marker);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<const N: usize> Default for FmtBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FmtBuf<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Display for FmtBuf<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Debug for FmtBuf<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Formats `args` into `buf`, returning the written [`str`].
+///
+/// # Errors
+///
+/// Returns [`Error`] if the formatted output does not fit in `buf`, or if a formatting
+/// implementation returns an error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::write::format_into;
+/// # use display_as_debug::wrap::DisplayAsDebug;
+/// let mut buf = [0; 32];
+/// let written = format_into(&mut buf, format_args!("{:?}", DisplayAsDebug("ready"))).unwrap();
+/// assert_eq!(written, "ready");
+///
+/// let mut small = [0; 2];
+/// assert!(format_into(&mut small, format_args!("{}", 123)).is_err(), "write should overflow");
+/// ```
+pub fn format_into<'a>(buf: &'a mut [u8], args: Arguments<'_>) -> core::result::Result<&'a str, Error> {
+    let mut writer = SliceWriter { buf, len: 0 };
+    writer.write_fmt(args)?;
+    let SliceWriter { buf, len } = writer;
+    core::str::from_utf8(&buf[..len]).map_err(|_| Error)
+}
+
+/// A [`Write`] sink over a byte slice, failing writes that overflow.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        let end = self.len + s.len();
+        self.buf.get_mut(self.len..end).ok_or(Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Returns the longest prefix of `s` that is at most `max` bytes, split on a char boundary.
+fn prefix(s: &str, max: usize) -> &str {
+    let mut end = max.min(s.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
@@ -0,0 +1,127 @@
+use core::fmt::{Debug, Display, Result, Write};
+
+/// A [`Write`] sink that discards its input, counting the number of bytes written.
+///
+/// Useful for measuring the length of formatted output without allocating.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::write::Counter;
+/// use core::fmt::Write;
+///
+/// let mut counter = Counter::new();
+/// write!(counter, "{:#?}", [1, 2]).unwrap();
+/// assert_eq!(counter.len(), 17);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Counter(usize);
+
+impl Counter {
+    /// Creates a new [`Counter`] that has counted nothing.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns the number of bytes written so far.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::Counter;
+    /// use core::fmt::Write;
+    ///
+    /// let mut counter = Counter::new();
+    /// counter.write_str("héllo").unwrap();
+    /// assert_eq!(counter.len(), 6);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0
+    }
+
+    /// Returns `true` if nothing has been written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::Counter;
+    /// assert!(Counter::new().is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Extension trait for measuring the length of a value's formatted output, without allocating.
+///
+/// Lengths are measured in bytes of UTF-8, making them suitable for sizing buffers. Implemented
+/// for every type. If the value's formatting implementation returns an error, the length written
+/// up to the error is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use display_as_debug::write::FormattedLen;
+/// # use display_as_debug::wrap::OpaqueOption;
+/// # use display_as_debug::types::TestValue;
+/// assert_eq!(TestValue::TEST.display_len(), r#"Display("test")"#.len());
+/// assert_eq!(TestValue::TEST.debug_len(), r#"Debug("test")"#.len());
+/// assert_eq!(OpaqueOption(Some(1)).debug_len(), "Some(..)".len());
+/// ```
+pub trait FormattedLen {
+    /// Returns the length of the value's [`Display`] output, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::FormattedLen;
+    /// assert_eq!(12345.display_len(), 5);
+    /// assert_eq!("héllo".display_len(), 6);
+    /// ```
+    fn display_len(&self) -> usize
+    where
+        Self: Display;
+
+    /// Returns the length of the value's [`Debug`] output, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use display_as_debug::write::FormattedLen;
+    /// assert_eq!("hi".debug_len(), 4);
+    /// assert_eq!([1, 2].debug_len(), 6);
+    /// ```
+    fn debug_len(&self) -> usize
+    where
+        Self: Debug;
+}
+
+impl<T: ?Sized> FormattedLen for T {
+    fn display_len(&self) -> usize
+    where
+        Self: Display,
+    {
+        let mut counter = Counter::new();
+        _ = write!(counter, "{self}");
+        counter.len()
+    }
+
+    fn debug_len(&self) -> usize
+    where
+        Self: Debug,
+    {
+        let mut counter = Counter::new();
+        _ = write!(counter, "{self:?}");
+        counter.len()
+    }
+}
@@ -0,0 +1,7 @@
+//! Allocation-free [`Write`](core::fmt::Write) sinks.
+
+mod buf;
+mod len;
+
+pub use buf::{FmtBuf, Overflow, format_into};
+pub use len::{Counter, FormattedLen};
@@ -3,6 +3,7 @@
 /// Macro for testing format output
 ///
 /// (test_name, ctor, fmt, expected)
+#[allow(unused_macros, reason = "Used only in some integration tests")]
 macro_rules! test_fmt {
     ($test_name:ident, $ctor:expr, $fmt:expr, $expected:expr) => {
         #[test]
@@ -54,6 +55,7 @@ macro_rules! test_get {
     };
 }
 
+#[allow(unused_imports, reason = "Used only in some integration tests")]
 pub(crate) use test_fmt;
 #[allow(unused_imports, reason = "Used only in some integration tests")]
 pub(crate) use test_get;
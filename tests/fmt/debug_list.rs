@@ -29,3 +29,16 @@ fn entries_display() {
     let list = List(vec![TestValue::DEFAULT, TestValue::DEFAULT]);
     assert_eq!(format!("{list:?}"), r#"[Display(()), Display(())]"#);
 }
+
+#[test]
+fn entry_bytes() {
+    struct List(&'static [u8]);
+
+    impl Debug for List {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.debug_list().entry_bytes(self.0).finish()
+        }
+    }
+
+    assert_eq!(format!("{:?}", List(b"a\"b\xff")), r#"[b"a\"b\xff"]"#);
+}
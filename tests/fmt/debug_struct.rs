@@ -65,3 +65,149 @@ fn field_opaque() {
 
     assert_eq!(format!("{:?}", Struct { test: vec![1] }), "Struct { test: .. }");
 }
+
+#[test]
+fn field_display_quoted() {
+    use display_as_debug::wrap::Quoted;
+
+    struct Struct {
+        name: &'static str,
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_display("name", &Quoted(self.name)).finish()
+        }
+    }
+
+    assert_eq!(format!("{:?}", Struct { name: "a\nb" }), r#"Struct { name: "a\nb" }"#);
+}
+
+#[test]
+fn field_as() {
+    use display_as_debug::wrap::{FormatAs, TypeNameMode};
+
+    struct Struct<T> {
+        test: T,
+        format: FormatAs,
+    }
+
+    impl<T: Debug + Display> Debug for Struct<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_as("test", &self.test, self.format).finish()
+        }
+    }
+
+    let format = |format| format!("{:?}", Struct { test: TestValue::TEST, format });
+    assert_eq!(format(FormatAs::Debug), r#"Struct { test: Debug("test") }"#);
+    assert_eq!(format(FormatAs::Display), r#"Struct { test: Display("test") }"#);
+    assert_eq!(format(FormatAs::Opaque), "Struct { test: .. }");
+    assert_eq!(format(FormatAs::TypeName(TypeNameMode::Short)), "Struct { test: TestValue<&str> }");
+}
+
+#[test]
+fn field_fn() {
+    struct Struct<F> {
+        callback: F,
+        boxed: Box<dyn Fn(i32) -> i32>,
+    }
+
+    impl<F> Debug for Struct<F> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct")
+                .field_fn::<Short>("callback", &self.callback)
+                .field_fn::<Full>("boxed", &self.boxed)
+                .finish()
+        }
+    }
+
+    let test = Struct { callback: i32::abs, boxed: Box::new(|x| x + 1) };
+    assert_eq!(
+        format!("{test:?}"),
+        "Struct { callback: <impl i32>::abs, boxed: dyn core::ops::function::Fn(i32) -> i32 }"
+    );
+}
+
+#[test]
+fn field_addr() {
+    struct Struct {
+        test: Vec<i32>,
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_addr::<Short>("test", &self.test).finish()
+        }
+    }
+
+    let test = Struct { test: vec![1] };
+    let expected = format!("Struct {{ test: Vec<i32>@{:p} }}", core::ptr::from_ref(&test.test));
+    assert_eq!(format!("{test:?}"), expected);
+}
+
+#[test]
+fn field_bytes() {
+    struct Struct {
+        test: Vec<u8>,
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_bytes("test", &self.test).finish()
+        }
+    }
+
+    assert_eq!(format!("{:?}", Struct { test: b"hi\n\x00".to_vec() }), r#"Struct { test: b"hi\n\0" }"#);
+}
+
+#[test]
+fn field_base64() {
+    struct Struct {
+        test: [u8; 3],
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_base64("test", &self.test).finish()
+        }
+    }
+
+    assert_eq!(format!("{:?}", Struct { test: *b"foo" }), "Struct { test: Zm9v }");
+}
+
+#[test]
+fn field_duration() {
+    use std::time::Duration;
+
+    struct Struct {
+        test: Duration,
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_duration("test", &self.test).finish()
+        }
+    }
+
+    let test = Struct { test: Duration::from_millis(61_250) };
+    assert_eq!(format!("{test:?}"), "Struct { test: 1m 1s }");
+    assert_eq!(format!("{test:.2?}"), "Struct { test: 1m 1.25s }");
+}
+
+#[test]
+fn field_ip_masked() {
+    use std::net::{IpAddr, Ipv6Addr};
+
+    struct Struct {
+        test: IpAddr,
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_ip_masked("test", &self.test).finish()
+        }
+    }
+
+    let test = Struct { test: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0xabcd, 0x12, 0, 0, 0, 1)) };
+    assert_eq!(format!("{test:?}"), "Struct { test: 2001:db8:abcd::/48 }");
+}
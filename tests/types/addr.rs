@@ -0,0 +1,81 @@
+//! Integration tests for Addr and SharedAddr
+
+use display_as_debug::types::{Addr, Full, Short};
+
+fn addr_of<T: ?Sized>(value: &T) -> String {
+    format!("{:p}", core::ptr::from_ref(value).cast::<()>())
+}
+
+#[test]
+fn short() {
+    let value = vec![1];
+    assert_eq!(format!("{:?}", Addr::new::<Short>(&value)), format!("Vec<i32>@{}", addr_of(&value)));
+}
+
+#[test]
+fn full() {
+    let value = vec![1];
+    assert_eq!(format!("{:?}", Addr::new::<Full>(&value)), format!("alloc::vec::Vec<i32>@{}", addr_of(&value)));
+}
+
+#[test]
+fn unsized_str() {
+    let value: &str = "text";
+    assert_eq!(format!("{:?}", Addr::new::<Short>(value)), format!("str@{}", addr_of(value)));
+}
+
+#[test]
+fn aliases_match() {
+    let value = vec![1];
+    let (a, b) = (&value, &value);
+    assert_eq!(format!("{:?}", Addr::new::<Short>(a)), format!("{:?}", Addr::new::<Short>(b)));
+}
+
+#[test]
+fn copies_differ() {
+    let (a, b) = (vec![1], vec![1]);
+    assert_ne!(format!("{:?}", Addr::new::<Short>(&a)), format!("{:?}", Addr::new::<Short>(&b)));
+}
+
+#[cfg(feature = "alloc")]
+mod shared {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use display_as_debug::types::{SharedAddr, Short};
+
+    use super::addr_of;
+
+    #[test]
+    fn rc() {
+        let shared = Rc::new(42);
+        let _clone = Rc::clone(&shared);
+        let _weak = Rc::downgrade(&shared);
+
+        assert_eq!(
+            format!("{:?}", SharedAddr::new::<Short>(&shared)),
+            format!("Rc {{ addr: i32@{}, strong: 2, weak: 1, value: 42 }}", addr_of(&*shared))
+        );
+    }
+
+    #[test]
+    fn arc() {
+        let shared = Arc::new("text");
+
+        assert_eq!(
+            format!("{:?}", SharedAddr::new::<Short>(&shared)),
+            format!(r#"Arc {{ addr: &str@{}, strong: 1, weak: 0, value: "text" }}"#, addr_of(&*shared))
+        );
+    }
+
+    #[test]
+    fn clones_match() {
+        let shared = Arc::new(vec![1]);
+        let clone = Arc::clone(&shared);
+
+        assert_eq!(
+            format!("{:?}", SharedAddr::new::<Short>(&shared)),
+            format!("{:?}", SharedAddr::new::<Short>(&clone))
+        );
+    }
+}
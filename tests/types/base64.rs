@@ -0,0 +1,37 @@
+//! Integration tests for Base64
+
+use crate::common::*;
+use display_as_debug::types::Base64;
+
+/// Test vectors from RFC 4648 section 10.
+mod rfc4648 {
+    use super::*;
+
+    test_fmt!(empty, Base64::new(b""), "{}", "");
+    test_fmt!(f, Base64::new(b"f"), "{}", "Zg==");
+    test_fmt!(fo, Base64::new(b"fo"), "{}", "Zm8=");
+    test_fmt!(foo, Base64::new(b"foo"), "{}", "Zm9v");
+    test_fmt!(foob, Base64::new(b"foob"), "{}", "Zm9vYg==");
+    test_fmt!(fooba, Base64::new(b"fooba"), "{}", "Zm9vYmE=");
+    test_fmt!(foobar, Base64::new(b"foobar"), "{}", "Zm9vYmFy");
+}
+
+mod alphabet {
+    use super::*;
+
+    const BYTES: [u8; 3] = [0xfb, 0xef, 0xff];
+
+    test_fmt!(standard, Base64::new(BYTES), "{}", "++//");
+    test_fmt!(url_safe, Base64::new(BYTES).url_safe(), "{}", "--__");
+}
+
+mod unpadded {
+    use super::*;
+
+    test_fmt!(one, Base64::new(b"f").unpadded(), "{}", "Zg");
+    test_fmt!(two, Base64::new(b"fo").unpadded(), "{}", "Zm8");
+    test_fmt!(three, Base64::new(b"foo").unpadded(), "{}", "Zm9v");
+    test_fmt!(url_safe, Base64::new([0xfb, 0xff]).url_safe().unpadded(), "{}", "-_8");
+}
+
+test_fmt!(debug, Base64::new(vec![0xde, 0xad, 0xbe, 0xef]), "{:?}", "3q2+7w==");
@@ -0,0 +1,36 @@
+//! Integration tests for ByteSize and DecimalByteSize
+
+use crate::common::*;
+use display_as_debug::types::{ByteSize, DecimalByteSize};
+
+mod binary {
+    use super::*;
+
+    test_fmt!(zero, ByteSize(0), "{}", "0 B");
+    test_fmt!(bytes, ByteSize(1023), "{}", "1023 B");
+    test_fmt!(kib, ByteSize(1024), "{}", "1.0 KiB");
+    test_fmt!(mib, ByteSize(73_400_320), "{}", "70.0 MiB");
+    test_fmt!(max, ByteSize(u64::MAX), "{}", "16.0 EiB");
+    test_fmt!(precision, ByteSize(1_500), "{:.3}", "1.465 KiB");
+    test_fmt!(precision_zero, ByteSize(1_536), "{:.0}", "2 KiB");
+    test_fmt!(precision_bytes, ByteSize(100), "{:.2}", "100 B");
+    test_fmt!(precision_capped, ByteSize(1024), "{:.30}", "1.000000000000000000 KiB");
+    test_fmt!(carry, ByteSize(1_048_575), "{}", "1.0 MiB");
+    test_fmt!(debug, ByteSize(2048), "{:?}", "2.0 KiB");
+    test_fmt!(width, ByteSize(1024), "{:9}", "1.0 KiB  ");
+    test_fmt!(width_right, ByteSize(1024), "{:>9}", "  1.0 KiB");
+    test_fmt!(width_center_precision, ByteSize(1_500), "{:*^11.2}", "*1.46 KiB**");
+    test_fmt!(longest, ByteSize(1_048_575 * 1024), "{:.18}", "1023.999023437500000000 MiB");
+}
+
+mod decimal {
+    use super::*;
+
+    test_fmt!(bytes, DecimalByteSize(999), "{}", "999 B");
+    test_fmt!(kb, DecimalByteSize(1_000), "{}", "1.0 kB");
+    test_fmt!(mb, DecimalByteSize(73_400_320), "{}", "73.4 MB");
+    test_fmt!(max, DecimalByteSize(u64::MAX), "{}", "18.4 EB");
+    test_fmt!(carry, DecimalByteSize(999_999), "{:.2}", "1.00 MB");
+    test_fmt!(debug, DecimalByteSize(1_500), "{:?}", "1.5 kB");
+    test_fmt!(width_right, DecimalByteSize(999), "{:>7?}", "  999 B");
+}
@@ -0,0 +1,29 @@
+//! Integration tests for ByteStr
+
+use crate::common::*;
+use display_as_debug::types::ByteStr;
+
+mod debug {
+    use super::*;
+
+    test_fmt!(text, ByteStr::new(b"hello"), "{:?}", r#"b"hello""#);
+    test_fmt!(empty, ByteStr::new(b""), "{:?}", r#"b"""#);
+    test_fmt!(escapes, ByteStr::new(b"\"\\\n\r\t\0'"), "{:?}", r#"b"\"\\\n\r\t\0'""#);
+    test_fmt!(non_ascii, ByteStr::new(b"\x01\x7f\xff"), "{:?}", r#"b"\x01\x7f\xff""#);
+    test_fmt!(utf8, ByteStr::new("é"), "{:?}", r#"b"\xc3\xa9""#);
+    test_fmt!(truncated, ByteStr::new(b"hello").with_max_len(2), "{:?}", r#"b"he".."#);
+    test_fmt!(max_len_exact, ByteStr::new(b"hello").with_max_len(5), "{:?}", r#"b"hello""#);
+}
+
+mod display {
+    use super::*;
+
+    test_fmt!(text, ByteStr::new(b"hello"), "{}", "hello");
+    test_fmt!(utf8, ByteStr::new("héllo"), "{}", "héllo");
+    test_fmt!(invalid, ByteStr::new(b"a\xffb\xc3"), "{}", "a\u{FFFD}b\u{FFFD}");
+    test_fmt!(truncated, ByteStr::new(b"hello").with_max_len(2), "{}", "he..");
+    test_fmt!(truncated_char, ByteStr::new("é").with_max_len(1), "{}", "..");
+    test_fmt!(truncated_mid_char, ByteStr::new("héllo").with_max_len(2), "{}", "h..");
+    test_fmt!(truncated_after_char, ByteStr::new("héllo").with_max_len(3), "{}", "hé..");
+    test_fmt!(truncated_invalid, ByteStr::new(b"a\xff\xffb").with_max_len(2), "{}", "a\u{FFFD}..");
+}
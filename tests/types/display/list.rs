@@ -0,0 +1,23 @@
+use crate::common::*;
+use display_as_debug::types::{DisplayList, TestValue};
+
+const EXPECTED: &str = "[Display(1), Display(2)]";
+const VALUES: [TestValue<i32>; 2] = [TestValue(1), TestValue(2)];
+
+test_fmt!(array, DisplayList(&VALUES), "{:?}", EXPECTED);
+test_fmt!(slice, DisplayList(VALUES.as_slice()), "{:?}", EXPECTED);
+test_fmt!(vec, DisplayList(&VALUES.to_vec()), "{:?}", EXPECTED);
+test_fmt!(from, DisplayList::from(&VALUES), "{:?}", EXPECTED);
+test_fmt!(empty, DisplayList(&Vec::<TestValue>::new()), "{:?}", "[]");
+test_fmt!(pretty, DisplayList(&[TestValue(1)]), "{:#?}", "[\n    Display(1),\n]");
+
+#[test]
+fn derive() {
+    #[derive(Debug)]
+    #[allow(dead_code, reason = "Testing")]
+    struct Struct<'a> {
+        values: DisplayList<'a, [TestValue<i32>]>,
+    }
+
+    assert_eq!(format!("{:?}", Struct { values: DisplayList(&VALUES) }), format!("Struct {{ values: {EXPECTED} }}"));
+}
@@ -0,0 +1,13 @@
+use crate::common::*;
+use display_as_debug::types::{DisplayMap, TestValue};
+use std::collections::BTreeMap;
+
+const EXPECTED: &str = r#"{"a": Display(1), "b": Display(2)}"#;
+
+fn values() -> BTreeMap<&'static str, TestValue<i32>> {
+    BTreeMap::from([("a", TestValue(1)), ("b", TestValue(2))])
+}
+
+test_fmt!(map, DisplayMap(&values()), "{:?}", EXPECTED);
+test_fmt!(from, DisplayMap::from(&values()), "{:?}", EXPECTED);
+test_fmt!(empty, DisplayMap(&BTreeMap::<i32, TestValue>::new()), "{:?}", "{}");
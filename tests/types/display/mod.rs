@@ -0,0 +1,5 @@
+//! Integration tests for Display collection types
+
+mod list;
+mod map;
+mod set;
@@ -0,0 +1,14 @@
+use crate::common::*;
+use display_as_debug::types::{DisplaySet, TestValue};
+use std::collections::BTreeSet;
+
+const EXPECTED: &str = "{Display(1), Display(2)}";
+
+fn values() -> BTreeSet<TestValue<i32>> {
+    BTreeSet::from([TestValue(1), TestValue(2)])
+}
+
+test_fmt!(set, DisplaySet(&values()), "{:?}", EXPECTED);
+test_fmt!(array, DisplaySet(&[TestValue(1), TestValue(2)]), "{:?}", EXPECTED);
+test_fmt!(from, DisplaySet::from(&values()), "{:?}", EXPECTED);
+test_fmt!(empty, DisplaySet(&BTreeSet::<TestValue>::new()), "{:?}", "{}");
@@ -0,0 +1,71 @@
+//! Integration tests for HumanDuration and IsoDuration
+
+use core::time::Duration;
+
+use crate::common::*;
+use display_as_debug::types::{HumanDuration, IsoDuration, TimeUnit};
+
+mod human {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(90_061);
+
+    test_fmt!(zero, HumanDuration::new(Duration::ZERO), "{}", "0s");
+    test_fmt!(compound, HumanDuration::new(DAY), "{}", "1d 1h 1m 1s");
+    test_fmt!(skips_zero, HumanDuration::new(Duration::from_secs(86_401)), "{}", "1d 1s");
+    test_fmt!(truncated, HumanDuration::new(Duration::from_millis(1_999)), "{}", "1s");
+    test_fmt!(precision, HumanDuration::new(Duration::from_nanos(1_234_567_891)), "{:.2}", "1.23s");
+    test_fmt!(precision_fraction_only, HumanDuration::new(Duration::from_millis(60_500)), "{:.1}", "1m 0.5s");
+    test_fmt!(precision_zero_fraction, HumanDuration::new(Duration::from_secs(60)), "{:.1}", "1m");
+    test_fmt!(sub_unit, HumanDuration::new(Duration::from_micros(450)), "{}", "450µs");
+    test_fmt!(sub_unit_precision, HumanDuration::new(Duration::from_micros(1_500)), "{:.1}", "1.5ms");
+    test_fmt!(nanos, HumanDuration::new(Duration::from_nanos(7)), "{}", "7ns");
+    test_fmt!(largest, HumanDuration::new(DAY).with_largest(TimeUnit::Hours), "{}", "25h 1m 1s");
+    test_fmt!(smallest, HumanDuration::new(DAY).with_smallest(TimeUnit::Hours), "{}", "1d 1h");
+    test_fmt!(
+        smallest_nanos,
+        HumanDuration::new(Duration::from_nanos(1_002_003_004)).with_smallest(TimeUnit::Nanos),
+        "{}",
+        "1s 2ms 3µs 4ns"
+    );
+    test_fmt!(
+        inverted,
+        HumanDuration::new(DAY).with_largest(TimeUnit::Minutes).with_smallest(TimeUnit::Hours),
+        "{}",
+        "1501m"
+    );
+    test_fmt!(from, HumanDuration::from(Duration::from_secs(3_600)), "{:?}", "1h");
+    test_fmt!(width, HumanDuration::new(Duration::from_secs(61)), "{:8}", "1m 1s   ");
+    test_fmt!(width_right, HumanDuration::new(Duration::from_secs(61)), "{:>8}", "   1m 1s");
+    test_fmt!(width_multi_byte, HumanDuration::new(Duration::from_micros(450)), "{:_^9.1}", "_450.0µs_");
+    test_fmt!(
+        longest,
+        HumanDuration::new(Duration::MAX).with_largest(TimeUnit::Nanos).with_smallest(TimeUnit::Nanos),
+        "{:.18}",
+        "18446744073709551615999999999.000000000000000000ns"
+    );
+    test_fmt!(
+        longest_compound,
+        HumanDuration::new(Duration::MAX).with_smallest(TimeUnit::Nanos),
+        "{:.18}",
+        "213503982334601d 7h 15s 999ms 999µs 999.000000000000000000ns"
+    );
+}
+
+mod iso {
+    use super::*;
+
+    test_fmt!(zero, IsoDuration(Duration::ZERO), "{}", "PT0S");
+    test_fmt!(hours_minutes, IsoDuration(Duration::from_secs(3_660)), "{}", "PT1H1M");
+    test_fmt!(all, IsoDuration(Duration::from_secs(90_061)), "{}", "PT25H1M1S");
+    test_fmt!(fraction, IsoDuration(Duration::from_millis(1_500)), "{}", "PT1.5S");
+    test_fmt!(fraction_nanos, IsoDuration(Duration::from_nanos(1)), "{}", "PT0.000000001S");
+    test_fmt!(fraction_with_hours, IsoDuration(Duration::from_millis(3_600_250)), "{}", "PT1H0.25S");
+    test_fmt!(precision, IsoDuration(Duration::from_nanos(1_234_567_891)), "{:.3}", "PT1.234S");
+    test_fmt!(precision_zero, IsoDuration(Duration::from_millis(1_500)), "{:.0}", "PT1S");
+    test_fmt!(precision_capped, IsoDuration(Duration::from_secs(1)), "{:.12}", "PT1.000000000S");
+    test_fmt!(debug, IsoDuration(Duration::from_secs(60)), "{:?}", "PT1M");
+    test_fmt!(width, IsoDuration(Duration::from_secs(60)), "{:6}", "PT1M  ");
+    test_fmt!(width_right_precision, IsoDuration(Duration::from_secs(1)), "{:>10.3}", "  PT1.000S");
+    test_fmt!(longest, IsoDuration(Duration::MAX), "{:.9}", "PT5124095576030431H15.999999999S");
+}
@@ -0,0 +1,66 @@
+//! Integration tests for FnName
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::common::*;
+use display_as_debug::types::{FnName, Full, Short};
+
+mod handlers {
+    pub fn on_login(_: &str) {}
+
+    pub fn closure() -> impl Fn() {
+        || {}
+    }
+
+    pub fn nested() -> impl Fn() -> Box<dyn Fn()> {
+        || Box::new(|| {})
+    }
+}
+
+mod full {
+    use super::*;
+
+    test_fmt!(fn_item, FnName::of::<Full>(&handlers::on_login), "{:?}", "types::fn_name::handlers::on_login");
+    test_fmt!(closure, FnName::of::<Full>(&handlers::closure()), "{:?}", "{closure@types::fn_name::handlers::closure}");
+    test_fmt!(
+        nested_closure,
+        FnName::of::<Full>(&handlers::nested()),
+        "{:?}",
+        "{closure@types::fn_name::handlers::nested}"
+    );
+    test_fmt!(
+        fn_pointer,
+        FnName::<fn(String) -> Vec<u8>>::FULL,
+        "{:?}",
+        "fn(alloc::string::String) -> alloc::vec::Vec<u8>"
+    );
+    test_fmt!(
+        method,
+        FnName::of::<Full>(&<Vec<u8> as Clone>::clone),
+        "{:?}",
+        "<alloc::vec::Vec<u8> as core::clone::Clone>::clone"
+    );
+}
+
+mod short {
+    use super::*;
+
+    test_fmt!(fn_item, FnName::of::<Short>(&handlers::on_login), "{:?}", "on_login");
+    test_fmt!(closure, FnName::of::<Short>(&handlers::closure()), "{:?}", "{closure@closure}");
+    test_fmt!(fn_pointer, FnName::<fn(String) -> Vec<u8>>::SHORT, "{:?}", "fn(String) -> Vec<u8>");
+    test_fmt!(method, FnName::of::<Short>(&<Vec<u8> as Clone>::clone), "{:?}", "<Vec<u8> as Clone>::clone");
+    test_fmt!(associated, FnName::of::<Short>(&Vec::<u8>::new), "{:?}", "Vec<u8>::new");
+}
+
+mod peeled {
+    use super::*;
+
+    const EXPECTED: &str = "dyn Fn(i32) + Send";
+
+    test_fmt!(reference, FnName::<&dyn Fn(i32)>::SHORT, "{:?}", "dyn Fn(i32)");
+    test_fmt!(boxed, FnName::<Box<dyn Fn(i32) + Send>>::SHORT, "{:?}", EXPECTED);
+    test_fmt!(rc, FnName::<Rc<dyn Fn(i32) + Send>>::SHORT, "{:?}", EXPECTED);
+    test_fmt!(arc, FnName::<Arc<dyn Fn(i32) + Send>>::SHORT, "{:?}", EXPECTED);
+    test_fmt!(boxed_closure, FnName::of::<Short>(&handlers::nested()()), "{:?}", "dyn Fn()");
+}
@@ -0,0 +1,25 @@
+//! Integration tests for Grouped
+
+use crate::common::*;
+use display_as_debug::types::Grouped;
+
+test_fmt!(zero, Grouped::new(0), "{}", "0");
+test_fmt!(small, Grouped::new(999), "{}", "999");
+test_fmt!(thousand, Grouped::new(1_000), "{}", "1_000");
+test_fmt!(million, Grouped::new(1_234_567), "{:?}", "1_234_567");
+test_fmt!(negative, Grouped::new(-1_234_567), "{}", "-1_234_567");
+test_fmt!(comma, Grouped::new(1_234_567_u32).with_separator(','), "{}", "1,234,567");
+test_fmt!(multi_byte, Grouped::new(1_234_567_u64).with_separator('\u{202f}'), "{}", "1\u{202f}234\u{202f}567");
+test_fmt!(from, Grouped::from(12_345_usize), "{}", "12_345");
+test_fmt!(i8_min, Grouped::new(i8::MIN), "{}", "-128");
+test_fmt!(isize_min, Grouped::new(isize::MIN), "{}", "-9_223_372_036_854_775_808");
+test_fmt!(u128_max, Grouped::new(u128::MAX), "{}", "340_282_366_920_938_463_463_374_607_431_768_211_455");
+test_fmt!(
+    u128_max_wide_separator,
+    Grouped::new(u128::MAX).with_separator('😀'),
+    "{}",
+    "340😀282😀366😀920😀938😀463😀463😀374😀607😀431😀768😀211😀455"
+);
+test_fmt!(width, Grouped::new(1_000), "{:>8}", "   1_000");
+test_fmt!(zero_pad, Grouped::new(-1_000), "{:08}", "-001_000");
+test_fmt!(sign, Grouped::new(1_000), "{:+}", "+1_000");
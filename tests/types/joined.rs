@@ -0,0 +1,26 @@
+use crate::common::*;
+use display_as_debug::types::{Joined, TestValue};
+
+const ITEMS: [&str; 3] = ["a", "b", "c"];
+
+mod display {
+    use super::*;
+
+    test_fmt!(slice, Joined::new(&ITEMS, ", "), "{}", "a, b, c");
+    test_fmt!(last, Joined::new(&ITEMS, ", ").with_last(" and "), "{}", "a, b and c");
+    test_fmt!(last_two, Joined::new(["a", "b"], ", ").with_last(" and "), "{}", "a and b");
+    test_fmt!(last_one, Joined::new(["a"], ", ").with_last(" and "), "{}", "a");
+    test_fmt!(empty, Joined::new(Vec::<i32>::new(), ", "), "{}", "");
+    test_fmt!(range, Joined::new(1..4, '/'), "{}", "1/2/3");
+    test_fmt!(precision, Joined::new([1.0, 2.25], ", "), "{:.1}", "1.0, 2.2");
+    test_fmt!(width, Joined::new([1, 2], ","), "{:>3}", "  1,  2");
+    test_fmt!(test_value, Joined::new([TestValue(1), TestValue(2)], "; "), "{}", "Display(1); Display(2)");
+}
+
+mod debug {
+    use super::*;
+
+    test_fmt!(slice, Joined::new(&ITEMS, ", "), "{:?}", "[a, b, c]");
+    test_fmt!(empty, Joined::new(Vec::<i32>::new(), ", "), "{:?}", "[]");
+    test_fmt!(test_value, Joined::new([TestValue(1)], "; "), "{:?}", "[Display(1)]");
+}
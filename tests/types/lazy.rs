@@ -0,0 +1,76 @@
+use crate::common::*;
+use display_as_debug::fmt::{DebugMapExt, DebugStructExt};
+use display_as_debug::types::{LazyDebug, LazyDisplay};
+use display_as_debug::{debug, display};
+use std::fmt::{Debug, Display, Formatter};
+
+const HOST: &str = "localhost";
+const PORT: u16 = 8080;
+
+mod lazy_display {
+    use super::*;
+
+    test_fmt!(display, display!("{HOST}:{PORT}"), "{}", "localhost:8080");
+    test_fmt!(debug, display!("{HOST}:{PORT}"), "{:?}", "localhost:8080");
+    test_fmt!(args, display!("{}-{:?}", 1, "two"), "{}", r#"1-"two""#);
+    test_fmt!(new, LazyDisplay::new(|f| f.write_str("new")), "{:?}", "new");
+}
+
+mod lazy_debug {
+    use super::*;
+
+    test_fmt!(display, debug!("{HOST}:{PORT}"), "{}", "localhost:8080");
+    test_fmt!(debug, debug!("{HOST}:{PORT}"), "{:?}", r#""localhost:8080""#);
+    test_fmt!(escaped, debug!("a\n{}", "b"), "{:?}", r#""a\nb""#);
+    test_fmt!(new, LazyDebug::new(|f| f.write_str("new")), "{:?}", r#""new""#);
+}
+
+fn moved(id: u32) -> impl Display + Debug {
+    display!(move "item #{id}")
+}
+
+fn moved_debug(id: u32) -> impl Display + Debug {
+    debug!(move "item #{id}")
+}
+
+test_fmt!(display_move, moved(7), "{:?}", "item #7");
+test_fmt!(debug_move, moved_debug(7), "{:?}", r#""item #7""#);
+
+#[test]
+fn stored() {
+    let host = String::from("example.com");
+    let addr = display!("{host}:{PORT}");
+    let copy = addr;
+    assert_eq!(format!("{addr} {copy}"), "example.com:8080 example.com:8080", "format should match expected");
+}
+
+#[test]
+fn field_display() {
+    struct Struct {
+        host: &'static str,
+        port: u16,
+    }
+
+    impl Debug for Struct {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Struct").field_display("addr", &display!("{}:{}", self.host, self.port)).finish()
+        }
+    }
+
+    assert_eq!(format!("{:?}", Struct { host: HOST, port: PORT }), "Struct { addr: localhost:8080 }");
+}
+
+#[test]
+fn entries_display() {
+    struct Map(Vec<(&'static str, u16)>);
+
+    impl Debug for Map {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_map()
+                .entries_display(self.0.iter().map(|(host, port)| (host, display!(move "{host}:{port}"))))
+                .finish()
+        }
+    }
+
+    assert_eq!(format!("{:?}", Map(vec![(HOST, PORT)])), r#"{"localhost": localhost:8080}"#);
+}
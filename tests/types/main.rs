@@ -1,5 +1,21 @@
 #[path = "../common/mod.rs"]
 mod common;
 
+mod addr;
+mod base64;
+mod byte_size;
+mod byte_str;
+mod display;
+mod duration;
+mod fn_name;
+mod grouped;
+mod joined;
+mod lazy;
+mod masked_email;
+mod masked_ip;
 mod opaque;
+mod ordinal;
+mod ratio;
+mod redacted_url;
 mod type_name;
+mod unix_time;
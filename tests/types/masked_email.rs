@@ -0,0 +1,14 @@
+//! Integration tests for MaskedEmail
+
+use crate::common::*;
+use display_as_debug::types::MaskedEmail;
+
+test_fmt!(display, MaskedEmail("jane.doe@example.com"), "{}", "j***@example.com");
+test_fmt!(debug, MaskedEmail("jane.doe@example.com"), "{:?}", "j***@example.com");
+test_fmt!(single_char, MaskedEmail("j@example.com"), "{}", "j***@example.com");
+test_fmt!(multi_byte, MaskedEmail("ünïcode@example.com"), "{}", "ü***@example.com");
+test_fmt!(last_at, MaskedEmail(r#""a@b"@example.com"#), "{}", "\"***@example.com");
+test_fmt!(empty_local, MaskedEmail("@example.com"), "{}", "***@example.com");
+test_fmt!(no_at, MaskedEmail("jane.doe"), "{}", "j***");
+test_fmt!(empty, MaskedEmail(""), "{}", "***");
+test_fmt!(owned, MaskedEmail(String::from("jane@example.com")), "{}", "j***@example.com");
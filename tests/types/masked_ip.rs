@@ -0,0 +1,60 @@
+//! Integration tests for MaskedIp
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::common::*;
+use display_as_debug::types::MaskedIp;
+
+const V4: Ipv4Addr = Ipv4Addr::new(203, 0, 113, 42);
+const V6: Ipv6Addr = Ipv6Addr::new(0x2001, 0xdb8, 0xabcd, 0x12, 0, 0, 0, 1);
+
+mod v4 {
+    use super::*;
+
+    test_fmt!(default, MaskedIp::new(V4), "{}", "203.0.113.x");
+    test_fmt!(debug, MaskedIp::new(V4), "{:?}", "203.0.113.x");
+    test_fmt!(octet, MaskedIp::new(V4).with_v4_prefix(16), "{}", "203.0.x.x");
+    test_fmt!(partial, MaskedIp::new(V4).with_v4_prefix(20), "{}", "203.0.112.x");
+    test_fmt!(zero, MaskedIp::new(V4).with_v4_prefix(0), "{}", "x.x.x.x");
+    test_fmt!(full, MaskedIp::new(V4).with_v4_prefix(32), "{}", "203.0.113.42");
+    test_fmt!(over, MaskedIp::new(V4).with_v4_prefix(40), "{}", "203.0.113.42");
+    test_fmt!(ignores_v6_prefix, MaskedIp::new(V4).with_v6_prefix(0), "{}", "203.0.113.x");
+    test_fmt!(from, MaskedIp::from(V4), "{}", "203.0.113.x");
+    test_fmt!(width, MaskedIp::new(Ipv4Addr::LOCALHOST), "{:12}", "127.0.0.x   ");
+    test_fmt!(width_right, MaskedIp::new(Ipv4Addr::LOCALHOST), "{:>16}", "       127.0.0.x");
+    test_fmt!(width_center, MaskedIp::new(V4), "{:-^15}", "--203.0.113.x--");
+}
+
+mod v6 {
+    use super::*;
+
+    test_fmt!(default, MaskedIp::new(V6), "{}", "2001:db8:abcd::/48");
+    test_fmt!(debug, MaskedIp::new(V6), "{:?}", "2001:db8:abcd::/48");
+    test_fmt!(partial, MaskedIp::new(V6).with_v6_prefix(36), "{}", "2001:db8:a000::/36");
+    test_fmt!(network, MaskedIp::new(V6).with_v6_prefix(64), "{}", "2001:db8:abcd:12::/64");
+    test_fmt!(zero, MaskedIp::new(V6).with_v6_prefix(0), "{}", "::/0");
+    test_fmt!(full, MaskedIp::new(V6).with_v6_prefix(128), "{}", "2001:db8:abcd:12::1/128");
+    test_fmt!(over, MaskedIp::new(V6).with_v6_prefix(200), "{}", "2001:db8:abcd:12::1/128");
+}
+
+mod ip_addr {
+    use super::*;
+
+    test_fmt!(v4, MaskedIp::new(IpAddr::V4(V4)), "{}", "203.0.113.x");
+    test_fmt!(v6, MaskedIp::new(IpAddr::V6(V6)), "{}", "2001:db8:abcd::/48");
+}
+
+mod socket_addr {
+    use super::*;
+
+    test_fmt!(v4, MaskedIp::new(SocketAddrV4::new(V4, 8080)), "{}", "203.0.113.x:8080");
+    test_fmt!(v6, MaskedIp::new(SocketAddrV6::new(V6, 443, 0, 0)), "{}", "[2001:db8:abcd::/48]:443");
+    test_fmt!(either_v4, MaskedIp::new(SocketAddr::new(IpAddr::V4(V4), 80)), "{:?}", "203.0.113.x:80");
+    test_fmt!(either_v6, MaskedIp::new(SocketAddr::new(IpAddr::V6(V6), 80)), "{:?}", "[2001:db8:abcd::/48]:80");
+    test_fmt!(
+        longest,
+        MaskedIp::new(SocketAddrV6::new(Ipv6Addr::from_bits(u128::MAX), u16::MAX, 0, 0)).with_v6_prefix(128),
+        "{:>52}",
+        " [ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128]:65535"
+    );
+}
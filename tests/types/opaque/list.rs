@@ -1,5 +1,5 @@
 use crate::common::*;
-use display_as_debug::types::OpaqueList;
+use display_as_debug::types::{Grouped, OpaqueList};
 
 const EXPECTED: &str = "[..: 100]";
 
@@ -7,3 +7,6 @@ test_fmt!(new, OpaqueList(100), "{:?}", EXPECTED);
 test_fmt!(display, OpaqueList(100), "{}", EXPECTED);
 test_fmt!(of, OpaqueList::of(0..100), "{:?}", EXPECTED);
 test_fmt!(from, OpaqueList::from(0..100), "{:?}", EXPECTED);
+test_fmt!(grouped, OpaqueList(1_234_567).grouped(), "{:?}", "[..: 1_234_567]");
+test_fmt!(grouped_comma, OpaqueList(Grouped::new(1_234_567).with_separator(',')), "{}", "[..: 1,234,567]");
+test_get!(count, OpaqueList(100), |list: &OpaqueList| list.0, 100_usize);
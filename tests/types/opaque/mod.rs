@@ -6,3 +6,4 @@ mod opaque;
 mod option;
 mod result;
 mod set;
+mod values;
@@ -1,5 +1,5 @@
 use crate::common::*;
-use display_as_debug::types::OpaqueSet;
+use display_as_debug::types::{Grouped, OpaqueSet};
 
 const EXPECTED: &str = "{..: 100}";
 
@@ -7,3 +7,6 @@ test_fmt!(new, OpaqueSet(100), "{:?}", EXPECTED);
 test_fmt!(display, OpaqueSet(100), "{}", EXPECTED);
 test_fmt!(of, OpaqueSet::of(0..100), "{:?}", EXPECTED);
 test_fmt!(from, OpaqueSet::from(0..100), "{:?}", EXPECTED);
+test_fmt!(grouped, OpaqueSet(1_234_567).grouped(), "{:?}", "{..: 1_234_567}");
+test_fmt!(grouped_comma, OpaqueSet(Grouped::new(1_234_567).with_separator(',')), "{}", "{..: 1,234,567}");
+test_get!(count, OpaqueSet(100), |set: &OpaqueSet| set.0, 100_usize);
@@ -0,0 +1,13 @@
+use crate::common::*;
+use display_as_debug::types::OpaqueValues;
+use std::collections::BTreeMap;
+
+const EXPECTED: &str = r#"{"a": .., "b": ..}"#;
+
+fn values() -> BTreeMap<&'static str, &'static str> {
+    BTreeMap::from([("a", "secret"), ("b", "hidden")])
+}
+
+test_fmt!(map, OpaqueValues(&values()), "{:?}", EXPECTED);
+test_fmt!(from, OpaqueValues::from(&values()), "{:?}", EXPECTED);
+test_fmt!(empty, OpaqueValues(&BTreeMap::<i32, i32>::new()), "{:?}", "{}");
@@ -0,0 +1,19 @@
+//! Integration tests for Ordinal
+
+use crate::common::*;
+use display_as_debug::types::Ordinal;
+
+test_fmt!(zero, Ordinal(0), "{}", "0th");
+test_fmt!(first, Ordinal(1), "{}", "1st");
+test_fmt!(second, Ordinal(2), "{}", "2nd");
+test_fmt!(third, Ordinal(3), "{:?}", "3rd");
+test_fmt!(fourth, Ordinal(4), "{}", "4th");
+test_fmt!(eleventh, Ordinal(11), "{}", "11th");
+test_fmt!(twelfth, Ordinal(12_u8), "{}", "12th");
+test_fmt!(thirteenth, Ordinal(13_u16), "{}", "13th");
+test_fmt!(twenty_first, Ordinal(21), "{}", "21st");
+test_fmt!(hundred_twelfth, Ordinal(112), "{}", "112th");
+test_fmt!(hundred_twenty_second, Ordinal(122), "{}", "122nd");
+test_fmt!(negative, Ordinal(-3), "{}", "-3rd");
+test_fmt!(i128_min, Ordinal(i128::MIN), "{}", "-170141183460469231731687303715884105728th");
+test_get!(suffix, Ordinal(1_001), Ordinal::suffix, "st");
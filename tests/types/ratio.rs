@@ -0,0 +1,31 @@
+//! Integration tests for Percent and Ratio
+
+use crate::common::*;
+use display_as_debug::types::{Percent, Ratio};
+
+mod percent {
+    use super::*;
+
+    test_fmt!(whole, Percent::new(21, 50), "{}", "42.0%");
+    test_fmt!(rounded, Percent::new(3, 7), "{}", "42.9%");
+    test_fmt!(precision, Percent::new(3, 7), "{:.2}", "42.86%");
+    test_fmt!(precision_zero, Percent::new(3, 7), "{:.0}", "43%");
+    test_fmt!(debug, Percent::new(1, 3), "{:?}", "33.3%");
+    test_fmt!(zero, Percent::new(0, 5), "{}", "0.0%");
+    test_fmt!(full, Percent::new(5, 5), "{}", "100.0%");
+    test_fmt!(over, Percent::new(3, 2), "{}", "150.0%");
+    test_fmt!(zero_whole, Percent::new(1, 0), "{}", "NaN%");
+    test_fmt!(max, Percent::new(u64::MAX, 1), "{:.16}", "1844674407370955161500.0000000000000000%");
+    test_fmt!(precision_capped, Percent::new(1, 3), "{:.20}", "33.3333333333333333%");
+}
+
+mod ratios {
+    use super::*;
+
+    test_fmt!(display, Ratio::new(3, 7), "{}", "3/7");
+    test_fmt!(debug, Ratio::new(3, 7), "{:?}", "3/7");
+    test_fmt!(alternate, Ratio::new(3, 7), "{:#}", "3/7 (42.9%)");
+    test_fmt!(alternate_precision, Ratio::new(3, 7), "{:#.0?}", "3/7 (43%)");
+    test_fmt!(zero_denominator, Ratio::new(3, 0), "{:#}", "3/0 (NaN%)");
+    test_fmt!(percent, Ratio::new(1, 8).percent(), "{}", "12.5%");
+}
@@ -13,6 +13,7 @@ mod short {
     test_fmt!(of, TypeNameList::<String, Short>::of(0..100), "{:?}", EXPECTED);
     test_fmt!(from, TypeNameList::<String, Short>::from(0..100), "{:?}", EXPECTED);
     test_get!(len, TypeNameList::<String, Short>::new(100), TypeNameList::len, 100);
+    test_fmt!(grouped, TypeNameList::<String, Short>::new(1_234_567).grouped(), "{:?}", "[<String>: 1_234_567]");
 }
 
 mod full {
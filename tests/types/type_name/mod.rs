@@ -6,3 +6,4 @@ mod result;
 mod set;
 #[allow(clippy::module_inception, reason = "Matches implementation module name")]
 mod type_name;
+mod values;
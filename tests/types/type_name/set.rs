@@ -14,6 +14,7 @@ mod short {
     test_fmt!(of, TypeNameSet::<String, Short>::of(0..100), "{:?}", EXPECTED);
     test_fmt!(from, TypeNameSet::<String, Short>::from(0..100), "{:?}", EXPECTED);
     test_get!(len, TypeNameSet::<String, Short>::new(100), TypeNameSet::len, 100);
+    test_fmt!(grouped, TypeNameSet::<String, Short>::new(1_234_567).grouped(), "{:?}", "{<String>: 1_234_567}");
 }
 
 mod full {
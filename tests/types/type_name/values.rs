@@ -0,0 +1,17 @@
+use crate::common::*;
+use display_as_debug::types::{Full, Short, TypeNameValues};
+use std::collections::BTreeMap;
+
+fn values() -> BTreeMap<&'static str, Vec<u8>> {
+    BTreeMap::from([("a", vec![1]), ("b", vec![2])])
+}
+
+test_fmt!(
+    full,
+    TypeNameValues::<_, Full>::new(&values()),
+    "{:?}",
+    r#"{"a": alloc::vec::Vec<u8>, "b": alloc::vec::Vec<u8>}"#
+);
+test_fmt!(short, TypeNameValues::<_, Short>::new(&values()), "{:?}", r#"{"a": Vec<u8>, "b": Vec<u8>}"#);
+test_fmt!(from, TypeNameValues::<_, Short>::from(&values()), "{:?}", r#"{"a": Vec<u8>, "b": Vec<u8>}"#);
+test_fmt!(empty, TypeNameValues::<_, Short>::new(&BTreeMap::<i32, i32>::new()), "{:?}", "{}");
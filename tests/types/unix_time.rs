@@ -0,0 +1,59 @@
+//! Integration tests for UnixTime
+
+use crate::common::*;
+use display_as_debug::types::{TimeUnit, UnixTime};
+
+const TIMESTAMP: i64 = 1_792_240_496;
+
+test_fmt!(epoch, UnixTime::from_secs(0), "{}", "1970-01-01T00:00:00Z");
+test_fmt!(secs, UnixTime::from_secs(TIMESTAMP), "{}", "2026-10-17T12:34:56Z");
+test_fmt!(debug, UnixTime::from_secs(TIMESTAMP), "{:?}", "2026-10-17T12:34:56Z");
+test_fmt!(alternate, UnixTime::from_secs(TIMESTAMP), "{:#}", "2026-10-17T12:34:56Z (1792240496)");
+test_fmt!(alternate_debug, UnixTime::from_millis(-1), "{:#?}", "1969-12-31T23:59:59.999Z (-1)");
+test_fmt!(millis, UnixTime::from_millis(TIMESTAMP * 1_000 + 120), "{}", "2026-10-17T12:34:56.12Z");
+test_fmt!(micros, UnixTime::from_micros(TIMESTAMP * 1_000_000 + 1), "{}", "2026-10-17T12:34:56.000001Z");
+test_fmt!(nanos, UnixTime::from_nanos(TIMESTAMP * 1_000_000_000), "{}", "2026-10-17T12:34:56Z");
+test_fmt!(minutes, UnixTime::new(1, TimeUnit::Minutes), "{}", "1970-01-01T00:01:00Z");
+test_fmt!(precision, UnixTime::from_secs(TIMESTAMP), "{:.3}", "2026-10-17T12:34:56.000Z");
+test_fmt!(precision_truncated, UnixTime::from_nanos(1_999_999_999), "{:.2}", "1970-01-01T00:00:01.99Z");
+test_fmt!(precision_zero, UnixTime::from_millis(1_500), "{:.0}", "1970-01-01T00:00:01Z");
+test_fmt!(negative, UnixTime::from_secs(-86_401), "{}", "1969-12-30T23:59:59Z");
+test_fmt!(leap_day, UnixTime::from_secs(951_782_400), "{}", "2000-02-29T00:00:00Z");
+test_fmt!(non_leap_century, UnixTime::from_secs(-2_203_891_200), "{}", "1900-03-01T00:00:00Z");
+test_fmt!(year_zero, UnixTime::from_secs(-62_167_219_200), "{}", "0000-01-01T00:00:00Z");
+test_fmt!(negative_year, UnixTime::from_secs(-62_167_219_201), "{}", "-0001-12-31T23:59:59Z");
+test_fmt!(large_year, UnixTime::from_secs(253_402_300_800), "{}", "+10000-01-01T00:00:00Z");
+test_fmt!(max, UnixTime::from_secs(i64::MAX), "{}", "+292277026596-12-04T15:30:07Z");
+test_fmt!(min, UnixTime::from_secs(i64::MIN), "{}", "-292277022657-01-27T08:29:52Z");
+test_fmt!(max_days, UnixTime::new(i64::MAX, TimeUnit::Days), "{}", "+25252734927768524-07-27T00:00:00Z");
+test_fmt!(width, UnixTime::from_secs(0), "{:22}", "1970-01-01T00:00:00Z  ");
+test_fmt!(width_right, UnixTime::from_secs(0), "{:>22}", "  1970-01-01T00:00:00Z");
+test_fmt!(width_center, UnixTime::from_secs(0), "{:*^23}", "*1970-01-01T00:00:00Z**");
+test_fmt!(width_precision, UnixTime::from_secs(0), "{:>26.3}", "  1970-01-01T00:00:00.000Z");
+test_fmt!(width_alternate, UnixTime::from_millis(-1), "{:>#31?}", "  1969-12-31T23:59:59.999Z (-1)");
+test_fmt!(width_narrow, UnixTime::from_secs(0), "{:4}", "1970-01-01T00:00:00Z");
+test_fmt!(
+    longest,
+    UnixTime::new(i64::MIN, TimeUnit::Days),
+    "{:#.9}",
+    "-25252734927764585-06-07T00:00:00.000000000Z (-9223372036854775808)"
+);
+
+#[cfg(feature = "std")]
+mod system_time {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    test_fmt!(
+        after_epoch,
+        UnixTime::from(UNIX_EPOCH + Duration::new(1, 5)),
+        "{:#}",
+        "1970-01-01T00:00:01.000000005Z (1000000005)"
+    );
+    test_fmt!(
+        before_epoch,
+        UnixTime::from(UNIX_EPOCH - Duration::from_millis(500)),
+        "{:#}",
+        "1969-12-31T23:59:59.5Z (-500000000)"
+    );
+}
@@ -0,0 +1,26 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::Both;
+use std::fmt::{Display, Formatter};
+
+/// A type whose [`Display`] output depends on the alternate flag.
+#[derive(Debug)]
+struct Alternate;
+
+impl Display for Alternate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if f.alternate() { "alternate" } else { "plain" })
+    }
+}
+
+test_fmt!(display, Both(TestValue::TEST), "{}", r#"Display("test") (Debug("test"))"#);
+test_fmt!(display_alternate, Both(Alternate), "{:#}", "alternate\nAlternate");
+test_fmt!(debug, Both(TestValue::TEST), "{:?}", r#"Both { display: Display("test"), debug: Debug("test") }"#);
+test_fmt!(
+    debug_alternate,
+    Both(TestValue::TEST),
+    "{:#?}",
+    "Both {\n    display: Display(\"test\"),\n    debug: Debug(\"test\"),\n}"
+);
+test_fmt!(str, Both("a\nb"), "{}", "a\nb (\"a\\nb\")");
+test_fmt!(str_alternate, Both("a\nb"), "{:#}", "a\nb\n\"a\\nb\"");
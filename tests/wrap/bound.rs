@@ -0,0 +1,50 @@
+use core::ops::Bound;
+
+use crate::common::*;
+use display_as_debug::types::{Full, Short, TestValue};
+use display_as_debug::wrap::{AsDisplay, BoundWith, OpaqueBound, TypeNameBound};
+
+const EXPECTED_UNBOUNDED: &str = "Unbounded";
+
+mod with {
+    use super::*;
+
+    test_fmt!(included, BoundWith(Bound::Included(TestValue::TEST), AsDisplay), "{:?}", r#"Included(Display("test"))"#);
+    test_fmt!(excluded, BoundWith(Bound::Excluded(TestValue::TEST), AsDisplay), "{:?}", r#"Excluded(Display("test"))"#);
+    test_fmt!(unbounded, BoundWith(Bound::<TestValue<&str>>::Unbounded, AsDisplay), "{:?}", EXPECTED_UNBOUNDED);
+}
+
+mod opaque {
+    use super::*;
+
+    const EXPECTED_INCLUDED: &str = "Included(..)";
+    const EXPECTED_EXCLUDED: &str = "Excluded(..)";
+
+    test_fmt!(included, OpaqueBound(Bound::Included(42)), "{:?}", EXPECTED_INCLUDED);
+    test_fmt!(excluded, OpaqueBound(Bound::Excluded(42)), "{:?}", EXPECTED_EXCLUDED);
+    test_fmt!(unbounded, OpaqueBound(Bound::<i32>::Unbounded), "{:?}", EXPECTED_UNBOUNDED);
+    test_fmt!(borrow_included, OpaqueBound::borrow(&Bound::Included(42)), "{:?}", EXPECTED_INCLUDED);
+    test_fmt!(borrow_excluded, OpaqueBound::borrow(&Bound::Excluded(42)), "{:?}", EXPECTED_EXCLUDED);
+    test_fmt!(marker_included, OpaqueBound::INCLUDED, "{:?}", EXPECTED_INCLUDED);
+    test_fmt!(marker_excluded, OpaqueBound::EXCLUDED, "{:?}", EXPECTED_EXCLUDED);
+    test_fmt!(marker_unbounded, OpaqueBound::UNBOUNDED, "{:?}", EXPECTED_UNBOUNDED);
+}
+
+mod type_name {
+    use super::*;
+
+    const EXPECTED_INCLUDED_FULL: &str = "Included(alloc::vec::Vec<i32>)";
+    const EXPECTED_INCLUDED_SHORT: &str = "Included(Vec<i32>)";
+    const EXPECTED_EXCLUDED_FULL: &str = "Excluded(alloc::vec::Vec<i32>)";
+    const EXPECTED_EXCLUDED_SHORT: &str = "Excluded(Vec<i32>)";
+
+    test_fmt!(new_full, TypeNameBound::new::<Full>(Bound::Included(vec![1])), "{:?}", EXPECTED_INCLUDED_FULL);
+    test_fmt!(new_short, TypeNameBound::new::<Short>(Bound::Included(vec![1])), "{:?}", EXPECTED_INCLUDED_SHORT);
+    test_fmt!(borrow_full, TypeNameBound::borrow::<Full>(&Bound::Excluded(vec![1])), "{:?}", EXPECTED_EXCLUDED_FULL);
+    test_fmt!(borrow_short, TypeNameBound::borrow::<Short>(&Bound::Excluded(vec![1])), "{:?}", EXPECTED_EXCLUDED_SHORT);
+    test_fmt!(marker_included_full, TypeNameBound::<Vec<i32>>::INCLUDED_FULL, "{:?}", EXPECTED_INCLUDED_FULL);
+    test_fmt!(marker_included_short, TypeNameBound::<Vec<i32>>::INCLUDED_SHORT, "{:?}", EXPECTED_INCLUDED_SHORT);
+    test_fmt!(marker_excluded_full, TypeNameBound::<Vec<i32>>::EXCLUDED_FULL, "{:?}", EXPECTED_EXCLUDED_FULL);
+    test_fmt!(marker_excluded_short, TypeNameBound::<Vec<i32>>::EXCLUDED_SHORT, "{:?}", EXPECTED_EXCLUDED_SHORT);
+    test_fmt!(marker_unbounded, TypeNameBound::<Vec<i32>>::UNBOUNDED, "{:?}", EXPECTED_UNBOUNDED);
+}
@@ -0,0 +1,81 @@
+use core::ops::ControlFlow;
+
+use crate::common::*;
+use display_as_debug::types::{Full, Short, TestValue};
+use display_as_debug::wrap::{AsDebug, AsDisplay, ControlFlowWith, OpaqueControlFlow, TypeNameControlFlow};
+
+mod with {
+    use super::*;
+
+    test_fmt!(
+        r#break,
+        ControlFlowWith(ControlFlow::<_, TestValue<&str>>::Break(TestValue::TEST), AsDisplay, AsDebug),
+        "{:?}",
+        r#"Break(Display("test"))"#
+    );
+    test_fmt!(
+        r#continue,
+        ControlFlowWith(ControlFlow::<TestValue<&str>, _>::Continue(TestValue::TEST), AsDisplay, AsDebug),
+        "{:?}",
+        r#"Continue(Debug("test"))"#
+    );
+}
+
+mod opaque {
+    use super::*;
+
+    const EXPECTED_BREAK: &str = "Break(..)";
+    const EXPECTED_CONTINUE: &str = "Continue(..)";
+
+    test_fmt!(r#break, OpaqueControlFlow(ControlFlow::<_, ()>::Break(42)), "{:?}", EXPECTED_BREAK);
+    test_fmt!(r#continue, OpaqueControlFlow(ControlFlow::<i32, _>::Continue(42)), "{:?}", EXPECTED_CONTINUE);
+    test_fmt!(borrow_break, OpaqueControlFlow::borrow(&ControlFlow::<_, ()>::Break(42)), "{:?}", EXPECTED_BREAK);
+    test_fmt!(
+        borrow_continue,
+        OpaqueControlFlow::borrow(&ControlFlow::<i32, _>::Continue(42)),
+        "{:?}",
+        EXPECTED_CONTINUE
+    );
+    test_fmt!(marker_break, OpaqueControlFlow::BREAK, "{:?}", EXPECTED_BREAK);
+    test_fmt!(marker_continue, OpaqueControlFlow::CONTINUE, "{:?}", EXPECTED_CONTINUE);
+}
+
+mod type_name {
+    use super::*;
+
+    const EXPECTED_BREAK_FULL: &str = "Break(alloc::vec::Vec<u8>)";
+    const EXPECTED_BREAK_SHORT: &str = "Break(Vec<u8>)";
+    const EXPECTED_CONTINUE_FULL: &str = "Continue(alloc::string::String)";
+    const EXPECTED_CONTINUE_SHORT: &str = "Continue(String)";
+
+    type Flow = ControlFlow<Vec<u8>, String>;
+
+    test_fmt!(new_full, TypeNameControlFlow::new::<Full>(Flow::Break(vec![1])), "{:?}", EXPECTED_BREAK_FULL);
+    test_fmt!(new_short, TypeNameControlFlow::new::<Short>(Flow::Break(vec![1])), "{:?}", EXPECTED_BREAK_SHORT);
+    test_fmt!(
+        borrow_full,
+        TypeNameControlFlow::borrow::<Full>(&Flow::Continue(String::new())),
+        "{:?}",
+        EXPECTED_CONTINUE_FULL
+    );
+    test_fmt!(
+        borrow_short,
+        TypeNameControlFlow::borrow::<Short>(&Flow::Continue(String::new())),
+        "{:?}",
+        EXPECTED_CONTINUE_SHORT
+    );
+    test_fmt!(marker_break_full, TypeNameControlFlow::<Vec<u8>, String>::BREAK_FULL, "{:?}", EXPECTED_BREAK_FULL);
+    test_fmt!(marker_break_short, TypeNameControlFlow::<Vec<u8>, String>::BREAK_SHORT, "{:?}", EXPECTED_BREAK_SHORT);
+    test_fmt!(
+        marker_continue_full,
+        TypeNameControlFlow::<Vec<u8>, String>::CONTINUE_FULL,
+        "{:?}",
+        EXPECTED_CONTINUE_FULL
+    );
+    test_fmt!(
+        marker_continue_short,
+        TypeNameControlFlow::<Vec<u8>, String>::CONTINUE_SHORT,
+        "{:?}",
+        EXPECTED_CONTINUE_SHORT
+    );
+}
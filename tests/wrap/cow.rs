@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+use crate::common::*;
+use display_as_debug::types::{Full, Short};
+use display_as_debug::wrap::{AsDisplay, CowWith, OpaqueCow, TypeNameCow};
+
+mod with {
+    use super::*;
+
+    test_fmt!(borrowed, CowWith(Cow::Borrowed("text"), AsDisplay), "{:?}", "Borrowed(text)");
+    test_fmt!(owned, CowWith(Cow::<str>::Owned("text".into()), AsDisplay), "{:?}", "Owned(text)");
+    test_fmt!(from, CowWith::<_, AsDisplay>::from(Cow::Borrowed("text")), "{:?}", "Borrowed(text)");
+}
+
+mod opaque {
+    use super::*;
+
+    const EXPECTED_BORROWED: &str = "Borrowed(..)";
+    const EXPECTED_OWNED: &str = "Owned(..)";
+
+    test_fmt!(borrowed, OpaqueCow(Cow::Borrowed("secret")), "{:?}", EXPECTED_BORROWED);
+    test_fmt!(owned, OpaqueCow(Cow::<str>::Owned("secret".into())), "{:?}", EXPECTED_OWNED);
+    test_fmt!(marker_borrowed, OpaqueCow::BORROWED, "{:?}", EXPECTED_BORROWED);
+    test_fmt!(marker_owned, OpaqueCow::OWNED, "{:?}", EXPECTED_OWNED);
+    test_fmt!(borrow_borrowed, OpaqueCow::borrow(&Cow::Borrowed("secret")), "{:?}", EXPECTED_BORROWED);
+    test_fmt!(borrow_owned, OpaqueCow::borrow(&Cow::<str>::Owned("secret".into())), "{:?}", EXPECTED_OWNED);
+}
+
+mod type_name {
+    use super::*;
+
+    test_fmt!(new_full, TypeNameCow::new::<Full>(Cow::Borrowed(&[1_u8][..])), "{:?}", "Borrowed([u8])");
+    test_fmt!(new_short, TypeNameCow::new::<Short>(Cow::<str>::Owned("text".into())), "{:?}", "Owned(str)");
+    test_fmt!(borrow_borrowed, TypeNameCow::borrow::<Short>(&Cow::Borrowed("text")), "{:?}", "Borrowed(str)");
+    test_fmt!(borrow_owned, TypeNameCow::borrow::<Full>(&Cow::<[u8]>::Owned(vec![1])), "{:?}", "Owned([u8])");
+    test_fmt!(marker_borrowed_full, TypeNameCow::<String>::BORROWED_FULL, "{:?}", "Borrowed(alloc::string::String)");
+    test_fmt!(marker_borrowed_short, TypeNameCow::<String>::BORROWED_SHORT, "{:?}", "Borrowed(String)");
+    test_fmt!(marker_owned_full, TypeNameCow::<String>::OWNED_FULL, "{:?}", "Owned(alloc::string::String)");
+    test_fmt!(marker_owned_short, TypeNameCow::<String>::OWNED_SHORT, "{:?}", "Owned(String)");
+}
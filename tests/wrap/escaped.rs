@@ -0,0 +1,24 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::{Escaped, Quoted};
+
+mod unquoted {
+    use super::*;
+
+    const EXPECTED: &str = r"a\nb\u{1b}[0m\t\'\\";
+
+    test_fmt!(display, Escaped("a\nb\x1b[0m\t'\\"), "{}", EXPECTED);
+    test_fmt!(debug, Escaped("a\nb\x1b[0m\t'\\"), "{:?}", EXPECTED);
+    test_fmt!(plain, Escaped("plain text"), "{}", "plain text");
+    test_fmt!(unicode, Escaped("héllo"), "{}", "héllo");
+    test_fmt!(test_value, Escaped(TestValue::TEST), "{}", r#"Display(\"test\")"#);
+}
+
+mod quoted {
+    use super::*;
+
+    test_fmt!(display, Quoted("it's\n\"x\""), "{}", format!("{:?}", "it's\n\"x\""));
+    test_fmt!(debug, Quoted("it's\n\"x\""), "{:?}", format!("{:?}", "it's\n\"x\""));
+    test_fmt!(empty, Quoted(""), "{}", r#""""#);
+    test_fmt!(test_value, Quoted(TestValue::TEST), "{}", r#""Display(\"test\")""#);
+}
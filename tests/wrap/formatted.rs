@@ -0,0 +1,31 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::{FormatAs, Formatted, TypeNameMode};
+
+const VALUE: TestValue<&str> = TestValue::TEST;
+
+mod debug {
+    use super::*;
+
+    test_fmt!(debug, Formatted(&VALUE, FormatAs::Debug), "{:?}", r#"Debug("test")"#);
+    test_fmt!(display, Formatted(&VALUE, FormatAs::Display), "{:?}", r#"Display("test")"#);
+    test_fmt!(opaque, Formatted(&VALUE, FormatAs::Opaque), "{:?}", "..");
+    test_fmt!(
+        type_name_full,
+        Formatted(&VALUE, FormatAs::TypeName(TypeNameMode::Full)),
+        "{:?}",
+        "display_as_debug::types::test_value::TestValue<&str>"
+    );
+    test_fmt!(type_name_short, Formatted(&VALUE, FormatAs::TypeName(TypeNameMode::Short)), "{:?}", "TestValue<&str>");
+    test_fmt!(unsized_type_name, Formatted("str", FormatAs::TypeName(TypeNameMode::Full)), "{:?}", "str");
+}
+
+mod display {
+    use super::*;
+
+    test_fmt!(debug, Formatted(&VALUE, FormatAs::Debug), "{}", r#"Debug("test")"#);
+    test_fmt!(display, Formatted(&VALUE, FormatAs::Display), "{}", r#"Display("test")"#);
+    test_fmt!(opaque, Formatted(&VALUE, FormatAs::Opaque), "{}", "..");
+}
+
+test_get!(default, FormatAs::default(), Clone::clone, FormatAs::Debug);
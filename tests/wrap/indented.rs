@@ -0,0 +1,43 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::{DisplayAsDebug, Indented};
+
+const REPORT: &str = "error: failed\ncaused by: timeout\n\nhint: retry";
+
+mod new {
+    use super::*;
+
+    test_fmt!(display, Indented::new(REPORT, "  "), "{}", "error: failed\n  caused by: timeout\n  \n  hint: retry");
+    test_fmt!(debug_pretty, Indented::new(vec![1], "  "), "{:#?}", "[\n      1,\n  ]");
+    test_fmt!(debug, Indented::new(vec![1], "  "), "{:?}", "[1]");
+    test_fmt!(single_line, Indented::new(TestValue::TEST, "  "), "{}", r#"Display("test")"#);
+    test_fmt!(
+        display_as_debug,
+        Indented::new(DisplayAsDebug(REPORT), "  "),
+        "{:?}",
+        "error: failed\n  caused by: timeout\n  \n  hint: retry"
+    );
+}
+
+mod all {
+    use super::*;
+
+    test_fmt!(display, Indented::all("a\nb", "> "), "{}", "> a\n> b");
+    test_fmt!(trailing_newline, Indented::all("a\n", "> "), "{}", "> a\n");
+    test_fmt!(empty, Indented::all("", "> "), "{}", "");
+}
+
+#[test]
+fn nested_in_struct() {
+    use std::fmt::{Debug, Formatter};
+
+    struct Report(&'static str);
+
+    impl Debug for Report {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Report").field("cause", &Indented::new(DisplayAsDebug(self.0), "    ")).finish()
+        }
+    }
+
+    assert_eq!(format!("{:#?}", Report("a\nb")), "Report {\n    cause: a\n        b,\n}");
+}
@@ -3,9 +3,19 @@
 #[path = "../common/mod.rs"]
 mod common;
 
+mod both;
+mod bound;
+mod control_flow;
+#[cfg(feature = "alloc")]
+mod cow;
 mod debug_as_display;
 mod display_as_debug;
+mod escaped;
+mod formatted;
+mod indented;
+mod one_line;
 mod opaque;
 mod option;
+mod poll;
 mod result;
 mod type_name;
@@ -0,0 +1,14 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::OneLine;
+
+const NESTED: &str = "Outer {\n    inner: Inner {\n        value: 1,\n    },\n}";
+
+test_fmt!(display, OneLine(NESTED), "{}", "Outer { inner: Inner { value: 1, }, }");
+test_fmt!(debug, OneLine(vec![1, 2]), "{:?}", "[1, 2]");
+test_fmt!(debug_pretty, OneLine(vec![1, 2]), "{:#?}", "[ 1, 2, ]");
+test_fmt!(crlf, OneLine("a\r\n  b"), "{}", "a b");
+test_fmt!(blank_lines, OneLine("a\n\n\n b"), "{}", "a b");
+test_fmt!(leading_trailing, OneLine("\n  a\n"), "{}", "a");
+test_fmt!(inner_spaces, OneLine("a  b"), "{}", "a  b");
+test_fmt!(single_line, OneLine(TestValue::TEST), "{}", r#"Display("test")"#);
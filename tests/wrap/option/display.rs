@@ -0,0 +1,31 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::DisplayOption;
+
+const EXPECTED_SOME: &str = r#"Some(Display("test"))"#;
+const EXPECTED_NONE: &str = "None";
+
+mod ctor {
+    use super::*;
+
+    test_fmt!(some, DisplayOption(Some(TestValue::TEST)), "{:?}", EXPECTED_SOME);
+    test_fmt!(none, DisplayOption(None::<TestValue>), "{:?}", EXPECTED_NONE);
+    test_fmt!(from, DisplayOption::from(Some(TestValue::TEST)), "{:?}", EXPECTED_SOME);
+}
+
+mod borrow {
+    use super::*;
+
+    test_fmt!(some, DisplayOption::borrow(&Some(TestValue::TEST)), "{:?}", EXPECTED_SOME);
+    test_fmt!(none, DisplayOption::borrow(&None::<TestValue>), "{:?}", EXPECTED_NONE);
+}
+
+test_fmt!(pretty, DisplayOption(Some(TestValue(1))), "{:#?}", "Some(\n    Display(1),\n)");
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(some, DisplayOption(Some(TestValue(1))), "{}", "Display(1)");
+    test_fmt!(none, DisplayOption(None::<TestValue>), "{}", "");
+    test_fmt!(padded_none, DisplayOption(None::<TestValue>), "[{:3}]", "[   ]");
+}
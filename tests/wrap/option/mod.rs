@@ -1,4 +1,7 @@
 //! Integration tests for Option wrapper types
 
+mod display;
 mod opaque;
+mod placeholder;
 mod type_name;
+mod with;
@@ -17,3 +17,11 @@ mod borrow {
     test_fmt!(some, OpaqueOption::borrow(&Some(42)), "{:?}", EXPECTED_SOME);
     test_fmt!(none, OpaqueOption::borrow(&None::<i32>), "{:?}", EXPECTED_NONE);
 }
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(some, OpaqueOption(Some(42)), "{}", "..");
+    test_fmt!(none, OpaqueOption(None::<i32>), "{}", "");
+    test_fmt!(padded, OpaqueOption(Some(42)), "{:>4}", "  ..");
+}
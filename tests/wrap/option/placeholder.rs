@@ -0,0 +1,16 @@
+use crate::common::*;
+use display_as_debug::types::{Short, TestValue};
+use display_as_debug::wrap::{DisplayOption, OpaqueOption, Placeholder, TypeNameOption};
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(some, Placeholder(DisplayOption(Some(TestValue(1))), "-"), "{}", "Display(1)");
+    test_fmt!(none, Placeholder(DisplayOption(None::<TestValue>), "-"), "{}", "-");
+    test_fmt!(opaque_some, Placeholder(OpaqueOption(Some(1)), "n/a"), "{}", "..");
+    test_fmt!(opaque_none, Placeholder(OpaqueOption(None::<i32>), "n/a"), "{}", "n/a");
+    test_fmt!(type_name_none, Placeholder(TypeNameOption::new::<Short>(None::<i32>), "-"), "{}", "-");
+    test_fmt!(padded_none, Placeholder(OpaqueOption(None::<i32>), "-"), "[{:^5}]", "[  -  ]");
+}
+
+test_fmt!(debug, Placeholder(OpaqueOption(None::<i32>), "-"), "{:?}", "None");
@@ -38,3 +38,12 @@ mod borrowed {
     test_fmt!(some_full, TypeNameOption::borrow::<Full>(&Some(vec![1])), "{:?}", EXPECTED_SOME_FULL);
     test_fmt!(some_short, TypeNameOption::borrow::<Short>(&Some(vec![1])), "{:?}", EXPECTED_SOME_SHORT);
 }
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(some_full, TypeNameOption::new::<Full>(Some(vec![1])), "{}", "alloc::vec::Vec<i32>");
+    test_fmt!(some_short, TypeNameOption::new::<Short>(Some(vec![1])), "{}", "Vec<i32>");
+    test_fmt!(none, TypeNameOption::new::<Short>(None::<i32>), "{}", "");
+    test_fmt!(borrowed, TypeNameOption::borrow::<Short>(&Some(vec![1])), "{}", "Vec<i32>");
+}
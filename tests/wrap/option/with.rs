@@ -0,0 +1,37 @@
+use crate::common::*;
+use display_as_debug::types::{Full, TestValue, TypeName};
+use display_as_debug::wrap::{AsDebug, AsDisplay, AsIs, AsOpaque, OpaqueOption, OptionWith, Placeholder};
+
+const SOME: Option<TestValue<&str>> = Some(TestValue::TEST);
+const NONE: Option<TestValue<&str>> = None;
+
+mod debug {
+    use super::*;
+
+    test_fmt!(as_is, OptionWith(SOME, AsIs), "{:?}", r#"Some(Debug("test"))"#);
+    test_fmt!(as_debug, OptionWith(SOME, AsDebug), "{:?}", r#"Some(Debug("test"))"#);
+    test_fmt!(as_display, OptionWith(SOME, AsDisplay), "{:?}", r#"Some(Display("test"))"#);
+    test_fmt!(as_opaque, OptionWith(SOME, AsOpaque), "{:?}", "Some(..)");
+    test_fmt!(as_type_name, OptionWith(SOME, TypeName::empty::<i32, Full>()), "{:?}", "Some(i32)");
+    test_fmt!(none, OptionWith(NONE, AsDebug), "{:?}", "None");
+    test_fmt!(pretty, OptionWith(SOME, AsOpaque), "{:#?}", "Some(\n    ..,\n)");
+}
+
+mod display {
+    use super::*;
+
+    test_fmt!(as_is, OptionWith(SOME, AsIs), "{}", r#"Display("test")"#);
+    test_fmt!(as_debug, OptionWith(SOME, AsDebug), "{}", r#"Debug("test")"#);
+    test_fmt!(as_display, OptionWith(SOME, AsDisplay), "{}", r#"Display("test")"#);
+    test_fmt!(none, OptionWith(NONE, AsDisplay), "{}", "");
+    test_fmt!(placeholder, Placeholder(OptionWith(NONE, AsDebug), "-"), "{}", "-");
+}
+
+test_fmt!(from, OptionWith::<_, AsOpaque>::from(SOME), "{:?}", "Some(..)");
+
+#[test]
+fn alias() {
+    let option: OptionWith<i32, AsOpaque> = OpaqueOption(Some(1));
+    assert_eq!(option, OptionWith(Some(1), AsOpaque));
+    assert_eq!(Option::from(option), Some(1));
+}
@@ -0,0 +1,44 @@
+use core::task::Poll;
+
+use crate::common::*;
+use display_as_debug::types::{Full, Short, TestValue};
+use display_as_debug::wrap::{AsDisplay, OpaquePoll, PollWith, TypeNamePoll};
+
+const EXPECTED_PENDING: &str = "Pending";
+
+mod with {
+    use super::*;
+
+    test_fmt!(ready, PollWith(Poll::Ready(TestValue::TEST), AsDisplay), "{:?}", r#"Ready(Display("test"))"#);
+    test_fmt!(pending, PollWith(Poll::<TestValue<&str>>::Pending, AsDisplay), "{:?}", EXPECTED_PENDING);
+    test_fmt!(from, PollWith::<_, AsDisplay>::from(Poll::Ready(TestValue::TEST)), "{:?}", r#"Ready(Display("test"))"#);
+}
+
+mod opaque {
+    use super::*;
+
+    const EXPECTED_READY: &str = "Ready(..)";
+
+    test_fmt!(ready, OpaquePoll(Poll::Ready(42)), "{:?}", EXPECTED_READY);
+    test_fmt!(pending, OpaquePoll(Poll::<i32>::Pending), "{:?}", EXPECTED_PENDING);
+    test_fmt!(borrow_ready, OpaquePoll::borrow(&Poll::Ready(42)), "{:?}", EXPECTED_READY);
+    test_fmt!(borrow_pending, OpaquePoll::borrow(&Poll::<i32>::Pending), "{:?}", EXPECTED_PENDING);
+    test_fmt!(marker_ready, OpaquePoll::READY, "{:?}", EXPECTED_READY);
+    test_fmt!(marker_pending, OpaquePoll::PENDING, "{:?}", EXPECTED_PENDING);
+}
+
+mod type_name {
+    use super::*;
+
+    const EXPECTED_READY_FULL: &str = "Ready(alloc::vec::Vec<i32>)";
+    const EXPECTED_READY_SHORT: &str = "Ready(Vec<i32>)";
+
+    test_fmt!(new_full, TypeNamePoll::new::<Full>(Poll::Ready(vec![1])), "{:?}", EXPECTED_READY_FULL);
+    test_fmt!(new_short, TypeNamePoll::new::<Short>(Poll::Ready(vec![1])), "{:?}", EXPECTED_READY_SHORT);
+    test_fmt!(new_pending, TypeNamePoll::new::<Short>(Poll::<i32>::Pending), "{:?}", EXPECTED_PENDING);
+    test_fmt!(borrow_full, TypeNamePoll::borrow::<Full>(&Poll::Ready(vec![1])), "{:?}", EXPECTED_READY_FULL);
+    test_fmt!(borrow_short, TypeNamePoll::borrow::<Short>(&Poll::Ready(vec![1])), "{:?}", EXPECTED_READY_SHORT);
+    test_fmt!(marker_ready_full, TypeNamePoll::<Vec<i32>>::READY_FULL, "{:?}", EXPECTED_READY_FULL);
+    test_fmt!(marker_ready_short, TypeNamePoll::<Vec<i32>>::READY_SHORT, "{:?}", EXPECTED_READY_SHORT);
+    test_fmt!(marker_pending, TypeNamePoll::<Vec<i32>>::PENDING, "{:?}", EXPECTED_PENDING);
+}
@@ -0,0 +1,33 @@
+use crate::common::*;
+use display_as_debug::types::TestValue;
+use display_as_debug::wrap::DisplayResult;
+
+const EXPECTED_OK: &str = "Ok(Display(1))";
+const EXPECTED_ERR: &str = r#"Err(Display("error"))"#;
+
+type TestResult = Result<TestValue<i32>, TestValue<&'static str>>;
+
+const OK: TestResult = Ok(TestValue(1));
+const ERR: TestResult = Err(TestValue("error"));
+
+mod ctor {
+    use super::*;
+
+    test_fmt!(ok, DisplayResult(OK), "{:?}", EXPECTED_OK);
+    test_fmt!(err, DisplayResult(ERR), "{:?}", EXPECTED_ERR);
+    test_fmt!(from, DisplayResult::from(OK), "{:?}", EXPECTED_OK);
+}
+
+mod borrow {
+    use super::*;
+
+    test_fmt!(ok, DisplayResult::borrow(&OK), "{:?}", EXPECTED_OK);
+    test_fmt!(err, DisplayResult::borrow(&ERR), "{:?}", EXPECTED_ERR);
+}
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(ok, DisplayResult(OK), "{}", "Display(1)");
+    test_fmt!(err, DisplayResult(ERR), "{}", r#"Display("error")"#);
+}
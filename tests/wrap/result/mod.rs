@@ -1,4 +1,6 @@
 //! Integration tests for Result wrapper types
 
+mod display;
 mod opaque;
 mod type_name;
+mod with;
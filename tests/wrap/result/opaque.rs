@@ -17,3 +17,24 @@ mod borrow {
     test_fmt!(ok, OpaqueResult::borrow(&Ok::<i32, &str>(42)), "{:?}", EXPECTED_OK);
     test_fmt!(err, OpaqueResult::borrow(&Err::<i32, &str>("error")), "{:?}", EXPECTED_ERR);
 }
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(ok, OpaqueResult(Ok::<i32, &str>(42)), "{}", "..");
+    test_fmt!(err, OpaqueResult(Err::<i32, &str>("error")), "{}", "error");
+}
+
+mod fully {
+    use super::*;
+    use display_as_debug::wrap::FullyOpaqueResult;
+
+    struct NotDebug;
+
+    test_fmt!(ok, FullyOpaqueResult(Ok::<i32, &str>(42)), "{:?}", "Ok(..)");
+    test_fmt!(err, FullyOpaqueResult(Err::<i32, NotDebug>(NotDebug)), "{:?}", "Err(..)");
+    test_fmt!(err_display, FullyOpaqueResult(Err::<i32, NotDebug>(NotDebug)), "{}", "..");
+    test_fmt!(borrow, FullyOpaqueResult::borrow(&Err::<i32, &str>("error")), "{:?}", "Err(..)");
+    test_fmt!(ok_marker, FullyOpaqueResult::OK, "{:?}", "Ok(..)");
+    test_fmt!(err_marker, FullyOpaqueResult::ERR, "{:?}", "Err(..)");
+}
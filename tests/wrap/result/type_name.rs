@@ -12,3 +12,26 @@ test_fmt!(err_full, TypeNameResult::new::<Full>(Err::<Vec<i32>, i32>(42)), "{:?}
 test_fmt!(err_short, TypeNameResult::new::<Short>(Err::<Vec<i32>, i32>(42)), "{:?}", EXPECTED_ERR);
 test_fmt!(from, TypeNameResult::<Vec<i32>, i32, Vec<i32>, Full>::from(Ok(vec![])), "{:?}", EXPECTED_OK_FULL);
 test_fmt!(borrow, TypeNameResult::borrow::<Full>(&Ok::<Vec<i32>, i32>(vec![])), "{:?}", EXPECTED_OK_FULL);
+
+mod fmt_display {
+    use super::*;
+
+    test_fmt!(ok_full, TypeNameResult::new::<Full>(Ok::<Vec<i32>, i32>(vec![])), "{}", "alloc::vec::Vec<i32>");
+    test_fmt!(ok_short, TypeNameResult::new::<Short>(Ok::<Vec<i32>, i32>(vec![])), "{}", "Vec<i32>");
+    test_fmt!(err, TypeNameResult::new::<Short>(Err::<Vec<i32>, i32>(42)), "{}", "42");
+}
+
+mod fully {
+    use super::*;
+    use display_as_debug::wrap::FullyTypeNameResult;
+
+    struct NotDebug;
+
+    test_fmt!(ok, FullyTypeNameResult::new::<Short>(Ok::<Vec<i32>, NotDebug>(vec![])), "{:?}", EXPECTED_OK_SHORT);
+    test_fmt!(err, FullyTypeNameResult::new::<Short>(Err::<i32, NotDebug>(NotDebug)), "{:?}", "Err(NotDebug)");
+    test_fmt!(err_display, FullyTypeNameResult::new::<Short>(Err::<i32, NotDebug>(NotDebug)), "{}", "NotDebug");
+    test_fmt!(borrow, FullyTypeNameResult::borrow::<Full>(&Err::<i32, i32>(42)), "{:?}", "Err(i32)");
+    test_fmt!(ok_marker, FullyTypeNameResult::<Vec<i32>, i32>::OK_SHORT, "{:?}", EXPECTED_OK_SHORT);
+    test_fmt!(err_marker, FullyTypeNameResult::<Vec<i32>, i32>::ERR_FULL, "{:?}", "Err(i32)");
+    test_fmt!(err_empty, FullyTypeNameResult::err_empty::<i32, u8, Short>(), "{:?}", "Err(u8)");
+}
@@ -0,0 +1,46 @@
+use crate::common::*;
+use display_as_debug::types::{Short, TestValue, TypeName};
+use display_as_debug::wrap::{AsDebug, AsDisplay, AsIs, AsOpaque, OpaqueResult, ResultWith};
+
+type TestResult = Result<TestValue<i32>, TestValue<&'static str>>;
+
+const OK: TestResult = Ok(TestValue(1));
+const ERR: TestResult = Err(TestValue("error"));
+
+mod debug {
+    use super::*;
+
+    test_fmt!(ok, ResultWith(OK, AsOpaque, AsDisplay), "{:?}", "Ok(..)");
+    test_fmt!(err, ResultWith(ERR, AsOpaque, AsDisplay), "{:?}", r#"Err(Display("error"))"#);
+    test_fmt!(err_as_is, ResultWith(ERR, AsOpaque, AsIs), "{:?}", r#"Err(Debug("error"))"#);
+    test_fmt!(
+        ok_type_name,
+        ResultWith(OK, TypeName::empty::<TestValue<i32>, Short>(), AsIs),
+        "{:?}",
+        "Ok(TestValue<i32>)"
+    );
+}
+
+mod display {
+    use super::*;
+
+    test_fmt!(ok, ResultWith(OK, AsDebug, AsIs), "{}", "Debug(1)");
+    test_fmt!(err, ResultWith(ERR, AsDebug, AsIs), "{}", r#"Display("error")"#);
+    test_fmt!(padded, ResultWith(OK, AsOpaque, AsIs), "[{:<4}]", "[..  ]");
+}
+
+test_fmt!(from, ResultWith::<_, _, AsOpaque, AsDebug>::from(ERR), "{:?}", r#"Err(Debug("error"))"#);
+
+#[test]
+fn alias() {
+    let result: ResultWith<i32, &str, AsOpaque, AsIs> = OpaqueResult(Ok(1));
+    assert_eq!(result, ResultWith(Ok(1), AsOpaque, AsIs));
+    assert_eq!(Result::from(result), Ok(1));
+}
+
+mod replace {
+    use super::*;
+
+    test_fmt!(ok, ResultWith(OK, AsDebug, AsIs).with_ok(AsOpaque), "{:?}", "Ok(..)");
+    test_fmt!(err, OpaqueResult(ERR).with_err(AsOpaque), "{:?}", "Err(..)");
+}
@@ -0,0 +1,113 @@
+use crate::common::*;
+use core::fmt::Write;
+use display_as_debug::types::{Short, TypeName};
+use display_as_debug::wrap::DisplayAsDebug;
+use display_as_debug::write::{FmtBuf, format_into};
+
+fn written<const N: usize>(mut buf: FmtBuf<N>, args: core::fmt::Arguments<'_>) -> (FmtBuf<N>, std::fmt::Result) {
+    let result = buf.write_fmt(args);
+    (buf, result)
+}
+
+mod error {
+    use super::*;
+
+    #[test]
+    fn fits() {
+        let (buf, result) = written(FmtBuf::<8>::new(), format_args!("{:?}", TypeName::<Vec<u8>>::SHORT));
+        assert_eq!(result, Ok(()), "write should succeed");
+        assert_eq!(buf.as_str(), "Vec<u8>", "contents should match expected");
+        assert!(!buf.is_overflowed(), "buffer should not overflow");
+    }
+
+    #[test]
+    fn exact() {
+        let (buf, result) = written(FmtBuf::<4>::new(), format_args!("{}", 1234));
+        assert_eq!(result, Ok(()), "write should succeed");
+        assert_eq!(buf.as_str(), "1234", "contents should match expected");
+    }
+
+    #[test]
+    fn overflow() {
+        let (first, second) = ("ab", "cde");
+        let (buf, result) = written(FmtBuf::<4>::new(), format_args!("{first}{second}"));
+        assert!(result.is_err(), "write should fail");
+        assert_eq!(buf.as_str(), "ab", "contents should hold writes before overflow");
+        assert!(buf.is_overflowed(), "buffer should overflow");
+    }
+
+    test_get!(empty, FmtBuf::<4>::new(), FmtBuf::is_empty, true);
+    test_get!(capacity, FmtBuf::<4>::new(), FmtBuf::capacity, 4);
+}
+
+mod truncate {
+    use super::*;
+
+    #[test]
+    fn overflow() {
+        let (buf, result) = written(FmtBuf::<8>::truncating("..."), format_args!("{}", "a long message"));
+        assert_eq!(result, Ok(()), "write should succeed");
+        assert_eq!(buf.as_str(), "a lon...", "contents should be truncated");
+        assert!(buf.is_overflowed(), "buffer should overflow");
+    }
+
+    #[test]
+    fn cuts_back_earlier_writes() {
+        let (first, second) = ("abcdef", "g");
+        let (buf, _) = written(FmtBuf::<6>::truncating("..."), format_args!("{first}{second}"));
+        assert_eq!(buf.as_str(), "abc...", "contents should be truncated");
+    }
+
+    #[test]
+    fn char_boundary() {
+        let (buf, _) = written(FmtBuf::<5>::truncating("~"), format_args!("{}", "aéééé"));
+        assert_eq!(buf.as_str(), "aé~", "contents should be truncated on a char boundary");
+    }
+
+    #[test]
+    fn discards_after_overflow() {
+        let (first, second) = ("ab", "cdefg");
+        let (buf, result) = written(FmtBuf::<4>::truncating("~"), format_args!("{first}{second}{first}"));
+        assert_eq!(result, Ok(()), "write should succeed");
+        assert_eq!(buf.as_str(), "abc~", "later writes should be discarded");
+    }
+
+    #[test]
+    fn marker_too_long() {
+        let (buf, _) = written(FmtBuf::<2>::truncating("..."), format_args!("{}", "abc"));
+        assert_eq!(buf.as_str(), "..", "marker should be truncated");
+    }
+
+    #[test]
+    fn clear() {
+        let (mut buf, _) = written(FmtBuf::<2>::truncating("~"), format_args!("{}", "abc"));
+        buf.clear();
+        assert!(buf.is_empty(), "buffer should be empty");
+        assert!(!buf.is_overflowed(), "buffer should not be overflowed");
+    }
+}
+
+mod fmt {
+    use super::*;
+
+    test_fmt!(display, written(FmtBuf::<8>::new(), format_args!("a\nb")).0, "{}", "a\nb");
+    test_fmt!(debug, written(FmtBuf::<8>::new(), format_args!("a\nb")).0, "{:?}", r#""a\nb""#);
+}
+
+mod format_into {
+    use super::*;
+
+    #[test]
+    fn fits() {
+        let mut buf = [0; 16];
+        let written = format_into(&mut buf, format_args!("{:?}", DisplayAsDebug("ready")));
+        assert_eq!(written, Ok("ready"), "written str should match expected");
+    }
+
+    #[test]
+    fn overflow() {
+        let mut buf = [0; 4];
+        let written = format_into(&mut buf, format_args!("{:?}", TypeName::empty::<Vec<u8>, Short>()));
+        assert!(written.is_err(), "write should fail");
+    }
+}
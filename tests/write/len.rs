@@ -0,0 +1,45 @@
+use crate::common::*;
+use core::fmt::Write;
+use display_as_debug::types::{Full, OpaqueList, Short, TestValue, TypeName};
+use display_as_debug::wrap::{DisplayAsDebug, OneLine, OpaqueOption, TypeNameResult};
+use display_as_debug::write::{Counter, FormattedLen};
+
+mod display_len {
+    use super::*;
+
+    test_get!(str, "héllo", FormattedLen::display_len, 6);
+    test_get!(test_value, TestValue::TEST, FormattedLen::display_len, r#"Display("test")"#.len());
+    test_get!(opaque_list, OpaqueList(100), FormattedLen::display_len, "[..: 100]".len());
+    test_get!(one_line, OneLine("a\n  b"), FormattedLen::display_len, 3);
+    test_get!(empty, "", FormattedLen::display_len, 0);
+}
+
+mod debug_len {
+    use super::*;
+
+    test_get!(str, "hi", FormattedLen::debug_len, 4);
+    test_get!(test_value, TestValue::TEST, FormattedLen::debug_len, r#"Debug("test")"#.len());
+    test_get!(display_as_debug, DisplayAsDebug(TestValue::TEST), FormattedLen::debug_len, 15);
+    test_get!(type_name, TypeName::<Vec<i32>>::FULL, FormattedLen::debug_len, "alloc::vec::Vec<i32>".len());
+    test_get!(type_name_short, TypeName::empty::<Vec<i32>, Short>(), FormattedLen::debug_len, "Vec<i32>".len());
+    test_get!(opaque_option, OpaqueOption(Some(1)), FormattedLen::debug_len, "Some(..)".len());
+    test_get!(
+        type_name_result,
+        TypeNameResult::new::<Full>(Ok::<i32, ()>(1)),
+        FormattedLen::debug_len,
+        "Ok(i32)".len()
+    );
+}
+
+mod counter {
+    use super::*;
+
+    #[test]
+    fn counts_writes() {
+        let mut counter = Counter::new();
+        write!(counter, "{}-{:#?}", 12, [1]).unwrap();
+        assert_eq!(counter.len(), "12-[\n    1,\n]".len(), "length should match expected");
+    }
+
+    test_get!(empty, Counter::default(), Counter::is_empty, true);
+}
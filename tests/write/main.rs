@@ -0,0 +1,7 @@
+//! Integration tests for write sinks
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod buf;
+mod len;